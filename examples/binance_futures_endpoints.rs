@@ -18,7 +18,7 @@ fn general() {
         Err(err) => {
             match err {
                 BinanceLibErrorKind::BinanceError(response) => match response.code {
-                    -1000_i16 => println!("An unknown error occured while processing the request"),
+                    -1000 => println!("An unknown error occured while processing the request"),
                     _ => println!("Non-catched code {}: {}", response.code, response.msg),
                 },
                 _ => println!("Other errors: {}.", err),
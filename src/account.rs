@@ -1,13 +1,19 @@
 use crate::util::{build_signed_request, is_start_time_valid};
 use crate::model::{
-    AccountInformation, Balance, Empty, Order, OrderCanceled, TradeHistory, Transaction,
+    AccountInformation, AccountSnapshot, AccountSnapshots, AccountStatus, ApiRestrictions,
+    ApiTradingStatus, ApiTradingStatusResponse, Balance, Empty, FuturesSnapshot, MarginSnapshot,
+    Order, OrderCanceled, SpotSnapshot, TradeHistory, Transaction, UserAsset,
 };
 use crate::client::Client;
 use crate::errors::{Result, SdkError};
+use crate::futures::account::FuturesAccount;
+use crate::savings::Savings;
 use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::de::DeserializeOwned;
 use crate::api::API;
-use crate::api::Spot;
+use crate::api::{Sapi, Spot};
 
 #[derive(Clone)]
 pub struct Account {
@@ -15,7 +21,7 @@ pub struct Account {
     pub recv_window: u64,
 }
 
-struct OrderRequest {
+pub(crate) struct OrderRequest {
     pub symbol: String,
     pub qty: f64,
     pub price: f64,
@@ -115,6 +121,108 @@ impl Display for TimeInForce {
     }
 }
 
+#[derive(Default)]
+pub struct SnapshotRequest {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Number of daily snapshots, 7 to 30.
+    pub limit: Option<u32>,
+}
+
+/// Problem found by `Account::preflight` that keeps the API key from trading spot, or
+/// a warning about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreflightIssue {
+    /// The account status is not "Normal".
+    AccountStatus(String),
+    /// Trading with the API key is locked until `planned_recover_time`.
+    TradingLocked {
+        planned_recover_time: u64,
+    },
+    ReadingDisabled,
+    SpotTradingDisabled,
+    /// The API key is not restricted to trusted IPs, so its trading permission
+    /// expires at `trading_authority_expiration_time`. An issue once that time has
+    /// passed, a warning before.
+    IpUnrestricted {
+        trading_authority_expiration_time: Option<u64>,
+    },
+}
+
+impl Display for PreflightIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AccountStatus(status) => write!(f, "account status is {}", status),
+            Self::TradingLocked {
+                planned_recover_time,
+            } => write!(f, "trading is locked until {}", planned_recover_time),
+            Self::ReadingDisabled => write!(f, "the API key cannot read"),
+            Self::SpotTradingDisabled => write!(f, "the API key cannot trade spot"),
+            Self::IpUnrestricted {
+                trading_authority_expiration_time: Some(expiration_time),
+            } => write!(
+                f,
+                "the API key has no IP restriction, trading expires at {}",
+                expiration_time
+            ),
+            Self::IpUnrestricted { .. } => write!(f, "the API key has no IP restriction"),
+        }
+    }
+}
+
+/// Result of `Account::preflight`.
+#[derive(Debug, Clone)]
+pub struct PreflightReport {
+    pub account_status: String,
+    pub trading_status: ApiTradingStatus,
+    pub restrictions: ApiRestrictions,
+    pub issues: Vec<PreflightIssue>,
+    /// Findings that do not keep the API key from trading yet.
+    pub warnings: Vec<PreflightIssue>,
+}
+
+impl PreflightReport {
+    /// Whether there are no issues; warnings are ignored.
+    pub fn is_ready(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Balances of one asset across wallets, see `Account::wallet_overview`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AssetOverview {
+    /// Free and locked spot balance.
+    pub spot: f64,
+    /// Free, locked and frozen funding balance.
+    pub funding: f64,
+    /// USDⓈ-M futures wallet balance, without unrealized profit.
+    pub futures: f64,
+    pub flexible_earn: f64,
+    pub locked_earn: f64,
+}
+
+impl AssetOverview {
+    pub fn total(&self) -> f64 {
+        self.spot + self.funding + self.futures + self.flexible_earn + self.locked_earn
+    }
+}
+
+/// Per-asset view of the spot, funding, USDⓈ-M futures and Simple Earn wallets.
+#[derive(Debug, Clone, Default)]
+pub struct WalletOverview {
+    pub assets: BTreeMap<String, AssetOverview>,
+}
+
+impl WalletOverview {
+    pub fn get(&self, asset: &str) -> Option<&AssetOverview> {
+        self.assets.get(asset)
+    }
+
+    fn entry(&mut self, asset: String) -> &mut AssetOverview {
+        self.assets.entry(asset).or_default()
+    }
+}
+
 impl Account {
     // Account Information
     pub fn get_account(&self) -> Result<AccountInformation> {
@@ -223,7 +331,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -246,7 +354,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -269,7 +377,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -292,7 +400,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -315,7 +423,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -338,7 +446,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -409,7 +517,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -432,7 +540,7 @@ impl Account {
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -518,7 +626,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -556,7 +664,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -594,7 +702,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -632,7 +740,7 @@ impl Account {
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -659,7 +767,7 @@ impl Account {
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client.post_signed(API::Spot(Spot::Order), request)
     }
@@ -686,7 +794,7 @@ impl Account {
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -794,37 +902,182 @@ impl Account {
         self.get_trades(symbol, start_time, end_time)
     }
 
-    fn get_trades<S>(&self, symbol: S, start_time: u64, end_time: u64) -> Result<Vec<TradeHistory>>
-    where
-        S: Into<String>,
-    {
-        let mut trades = self.trade_history_from(symbol, start_time)?;
-        trades.retain(|trade| trade.time <= end_time);
-        Ok(trades)
+    // Funding wallet balances, of ONE asset or of all assets if `None`
+    pub fn funding_assets(
+        &self, asset: Option<String>, need_btc_valuation: bool,
+    ) -> Result<Vec<UserAsset>> {
+        self.user_asset_balances(Sapi::FundingAsset, asset, need_btc_valuation)
     }
 
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+    // Positive spot balances, of ONE asset or of all assets if `None`
+    pub fn user_assets(
+        &self, asset: Option<String>, need_btc_valuation: bool,
+    ) -> Result<Vec<UserAsset>> {
+        self.user_asset_balances(Sapi::UserAsset, asset, need_btc_valuation)
+    }
 
-        order_parameters.insert("symbol".into(), order.symbol);
-        order_parameters.insert("side".into(), order.order_side.to_string());
-        order_parameters.insert("type".into(), order.order_type.to_string());
-        order_parameters.insert("quantity".into(), order.qty.to_string());
+    // Balances of every asset across the spot, funding, USDⓈ-M futures and Simple Earn wallets
+    pub fn wallet_overview(
+        &self, futures_account: &FuturesAccount, savings: &Savings,
+    ) -> Result<WalletOverview> {
+        let mut overview = WalletOverview::default();
+        for balance in self.get_account()?.balances {
+            let amount = balance.free.parse::<f64>()? + balance.locked.parse::<f64>()?;
+            if amount > 0.0 {
+                overview.entry(balance.asset).spot += amount;
+            }
+        }
+        for balance in self.funding_assets(None, false)? {
+            overview.entry(balance.asset).funding += balance.free + balance.locked + balance.freeze;
+        }
+        for balance in futures_account.account_balance()? {
+            if balance.balance != 0.0 {
+                overview.entry(balance.asset).futures += balance.balance;
+            }
+        }
+        for position in savings.simple_earn_flexible_positions(None) {
+            let position = position?;
+            overview.entry(position.asset).flexible_earn += position.total_amount;
+        }
+        for position in savings.simple_earn_locked_positions(None) {
+            let position = position?;
+            overview.entry(position.asset).locked_earn += position.amount;
+        }
+        Ok(overview)
+    }
+
+    // Account status, "Normal" unless the account is restricted
+    pub fn account_status(&self) -> Result<String> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed::<AccountStatus>(API::Savings(Sapi::AccountStatus), Some(request))
+            .map(|status| status.data)
+    }
+
+    // Trading status of the API key
+    pub fn api_trading_status(&self) -> Result<ApiTradingStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed::<ApiTradingStatusResponse>(
+                API::Savings(Sapi::ApiTradingStatus),
+                Some(request),
+            )
+            .map(|status| status.data)
+    }
+
+    // Permissions and IP restriction of the API key
+    pub fn api_restrictions(&self) -> Result<ApiRestrictions> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ApiRestrictions), Some(request))
+    }
+
+    // Daily snapshots of the spot wallet
+    pub fn spot_snapshots(
+        &self, snapshot_request: SnapshotRequest,
+    ) -> Result<Vec<AccountSnapshot<SpotSnapshot>>> {
+        self.account_snapshots("SPOT", snapshot_request)
+    }
+
+    // Daily snapshots of the cross margin wallet
+    pub fn margin_snapshots(
+        &self, snapshot_request: SnapshotRequest,
+    ) -> Result<Vec<AccountSnapshot<MarginSnapshot>>> {
+        self.account_snapshots("MARGIN", snapshot_request)
+    }
+
+    // Daily snapshots of the USDⓈ-M futures wallet
+    pub fn futures_snapshots(
+        &self, snapshot_request: SnapshotRequest,
+    ) -> Result<Vec<AccountSnapshot<FuturesSnapshot>>> {
+        self.account_snapshots("FUTURES", snapshot_request)
+    }
 
-        if let Some(stop_price) = order.stop_price {
-            order_parameters.insert("stopPrice".into(), stop_price.to_string());
+    // Check that the account and the API key can trade spot, before sending any order
+    pub fn preflight(&self) -> Result<PreflightReport> {
+        let account_status = self.account_status()?;
+        let trading_status = self.api_trading_status()?;
+        let restrictions = self.api_restrictions()?;
+
+        let mut issues = Vec::new();
+        if account_status != "Normal" {
+            issues.push(PreflightIssue::AccountStatus(account_status.clone()));
+        }
+        if trading_status.is_locked {
+            issues.push(PreflightIssue::TradingLocked {
+                planned_recover_time: trading_status.planned_recover_time,
+            });
+        }
+        if !restrictions.enable_reading {
+            issues.push(PreflightIssue::ReadingDisabled);
+        }
+        if !restrictions.enable_spot_and_margin_trading {
+            issues.push(PreflightIssue::SpotTradingDisabled);
+        }
+        let mut warnings = Vec::new();
+        if !restrictions.ip_restrict {
+            let expiration_time = restrictions.trading_authority_expiration_time;
+            let unrestricted = PreflightIssue::IpUnrestricted {
+                trading_authority_expiration_time: expiration_time,
+            };
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+            if expiration_time.is_some_and(|expiration_time| expiration_time <= now) {
+                issues.push(unrestricted);
+            } else {
+                warnings.push(unrestricted);
+            }
         }
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+        Ok(PreflightReport {
+            account_status,
+            trading_status,
+            restrictions,
+            issues,
+            warnings,
+        })
+    }
+
+    fn user_asset_balances(
+        &self, route: Sapi, asset: Option<String>, need_btc_valuation: bool,
+    ) -> Result<Vec<UserAsset>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if need_btc_valuation {
+            parameters.insert("needBtcValuation".into(), "true".into());
         }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.post_signed(API::Savings(route), request)
+    }
 
-        if let Some(client_order_id) = order.new_client_order_id {
-            order_parameters.insert("newClientOrderId".into(), client_order_id);
+    fn account_snapshots<T: DeserializeOwned>(
+        &self, snapshot_type: &str, snapshot_request: SnapshotRequest,
+    ) -> Result<Vec<AccountSnapshot<T>>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), snapshot_type.into());
+        if let Some(start_time) = snapshot_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = snapshot_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
         }
+        if let Some(limit) = snapshot_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<AccountSnapshots<T>>(API::Savings(Sapi::AccountSnapshot), Some(request))
+            .map(|snapshots| snapshots.snapshot_vos)
+    }
 
-        order_parameters
+    fn get_trades<S>(&self, symbol: S, start_time: u64, end_time: u64) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut trades = self.trade_history_from(symbol, start_time)?;
+        trades.retain(|trade| trade.time <= end_time);
+        Ok(trades)
     }
 
     fn build_quote_quantity_order(
@@ -849,3 +1102,27 @@ impl Account {
         order_parameters
     }
 }
+
+pub(crate) fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
+
+    if let Some(stop_price) = order.stop_price {
+        order_parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+
+    if order.price != 0.0 {
+        order_parameters.insert("price".into(), order.price.to_string());
+        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+    }
+
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+
+    order_parameters
+}
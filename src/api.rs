@@ -9,12 +9,27 @@ use crate::general::General;
 use crate::market::Market;
 use crate::userstream::UserStream;
 use crate::savings::Savings;
+use crate::margin::Margin;
+use crate::convert::Convert;
+use crate::sub_account::SubAccount;
+use crate::delivery::account::DeliveryAccount;
+use crate::delivery::general::DeliveryGeneral;
+use crate::delivery::market::DeliveryMarket;
+use crate::delivery::userstream::DeliveryUserStream;
+use crate::options::account::OptionsAccount;
+use crate::options::general::OptionsGeneral;
+use crate::options::market::OptionsMarket;
+use crate::options::userstream::OptionsUserStream;
+use crate::portfolio_margin::account::PortfolioMargin;
 
 #[allow(clippy::all)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    Delivery(Delivery),
+    Options(Options),
+    PortfolioMargin(Papi),
 }
 
 /// Endpoint for production and test orders.
@@ -46,15 +61,76 @@ pub enum Spot {
     UserDataStream,
 }
 
+#[derive(Clone, Copy)]
 pub enum Sapi {
     AllCoins,
     AssetDetail,
     DepositAddress,
-    SpotFuturesTransfer,
+    DepositAddresses,
+    DepositHistory,
+    Withdraw,
+    WithdrawHistory,
+    UniversalTransfer,
+    AccountStatus,
+    ApiTradingStatus,
+    ApiRestrictions,
+    AccountSnapshot,
+    FundingAsset,
+    UserAsset,
+    DustBtc,
+    Dust,
+    DustLog,
+    AssetDividend,
     SimpleEarnFlexible,
     SimpleEarnLocked,
+    SimpleEarnFlexibleList,
+    SimpleEarnLockedList,
+    SimpleEarnFlexibleSubscribe,
+    SimpleEarnLockedSubscribe,
+    SimpleEarnFlexibleRedeem,
+    SimpleEarnLockedRedeem,
+    SimpleEarnFlexibleSubscriptionRecord,
+    SimpleEarnLockedSubscriptionRecord,
+    SimpleEarnFlexibleRedemptionRecord,
+    SimpleEarnLockedRedemptionRecord,
+    SimpleEarnFlexibleRewardsRecord,
+    SimpleEarnLockedRewardsRecord,
+    SimpleEarnFlexiblePersonalLeftQuota,
+    SimpleEarnLockedPersonalLeftQuota,
+    MarginBorrowRepay,
+    MarginOrder,
+    MarginOrderOco,
+    MarginOrderList,
+    MarginOpenOrders,
+    MarginMaxBorrowable,
+    MarginMaxTransferable,
+    MarginInterestHistory,
+    MarginAccount,
+    MarginIsolatedAccount,
+    MarginUserDataStream,
+    SubAccountList,
+    SubAccountCreate,
+    SubAccountSpotSummary,
+    SubAccountFuturesSummary,
+    SubAccountMarginSummary,
+    SubAccountUniversalTransfer,
+    SubAccountFuturesEnable,
+    SubAccountMarginEnable,
+    SubAccountIpRestriction,
+    SubAccountIpRestrictionUpdate,
+    SubAccountIpList,
+    ConvertExchangeInfo,
+    ConvertAssetInfo,
+    ConvertGetQuote,
+    ConvertAcceptQuote,
+    ConvertOrderStatus,
+    ConvertLimitPlaceOrder,
+    ConvertLimitCancelOrder,
+    ConvertLimitOpenOrders,
+    ConvertTradeFlow,
 }
 
+#[derive(Clone, Copy)]
 pub enum Futures {
     Ping,
     Time,
@@ -77,6 +153,8 @@ pub enum Futures {
     AllOrders,
     UserTrades,
     Order,
+    BatchOrders,
+    OrderAmendment,
     PositionRisk,
     Balance,
     PositionSide,
@@ -88,13 +166,113 @@ pub enum Futures {
     TakerlongshortRatio,
     LvtKlines,
     IndexInfo,
+    LeverageBracket,
     ChangeInitialLeverage,
     MarginType,
     PositionMargin,
     Account,
+    OpenOrder,
     OpenOrders,
     UserDataStream,
     Income,
+    MultiAssetsMargin,
+    CommissionRate,
+    AdlQuantile,
+    ForceOrders,
+    PositionMarginHistory,
+    SymbolConfig,
+    AccountConfig,
+    ApiTradingStatus,
+    FeeBurn,
+    CountdownCancelAll,
+    IncomeAsyn,
+    IncomeAsynId,
+    OrderAsyn,
+    OrderAsynId,
+    TradeAsyn,
+    TradeAsynId,
+}
+
+/// Endpoints of the COIN-M (delivery) futures API.
+pub enum Delivery {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Trades,
+    HistoricalTrades,
+    AggTrades,
+    Klines,
+    ContinuousKlines,
+    IndexPriceKlines,
+    MarkPriceKlines,
+    PremiumIndex,
+    FundingRate,
+    Ticker24hr,
+    TickerPrice,
+    BookTicker,
+    OpenInterest,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    AllOrders,
+    UserTrades,
+    PositionRisk,
+    Balance,
+    Account,
+    PositionSide,
+    ChangeInitialLeverage,
+    MarginType,
+    PositionMargin,
+    Income,
+    UserDataStream,
+}
+
+/// Endpoints of the European options API.
+pub enum Options {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Klines,
+    Mark,
+    Ticker,
+    ExerciseHistory,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    HistoryOrders,
+    MarginAccount,
+    Position,
+    UserDataStream,
+}
+
+/// Endpoints of the Portfolio Margin API.
+///
+/// `Um*` routes trade USDⓈ-M futures and `Cm*` routes COIN-M futures from the
+/// unified account.
+#[derive(Clone, Copy)]
+pub enum Papi {
+    Ping,
+    UmOrder,
+    UmConditionalOrder,
+    UmOpenOrders,
+    UmAllOpenOrders,
+    UmPositionRisk,
+    UmLeverage,
+    CmOrder,
+    CmConditionalOrder,
+    CmOpenOrders,
+    CmAllOpenOrders,
+    CmPositionRisk,
+    CmLeverage,
+    MarginLoan,
+    RepayLoan,
+    Balance,
+    Account,
+    AutoCollection,
+    AssetCollection,
+    UserDataStream,
 }
 
 impl From<API> for String {
@@ -129,9 +307,86 @@ impl From<API> for String {
                 Sapi::AllCoins => "/sapi/v1/capital/config/getall",
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail",
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address",
-                Sapi::SpotFuturesTransfer => "/sapi/v1/futures/transfer",
+                Sapi::DepositAddresses => "/sapi/v1/capital/deposit/address/list",
+                Sapi::DepositHistory => "/sapi/v1/capital/deposit/hisrec",
+                Sapi::Withdraw => "/sapi/v1/capital/withdraw/apply",
+                Sapi::WithdrawHistory => "/sapi/v1/capital/withdraw/history",
+                Sapi::UniversalTransfer => "/sapi/v1/asset/transfer",
+                Sapi::AccountStatus => "/sapi/v1/account/status",
+                Sapi::ApiTradingStatus => "/sapi/v1/account/apiTradingStatus",
+                Sapi::ApiRestrictions => "/sapi/v1/account/apiRestrictions",
+                Sapi::AccountSnapshot => "/sapi/v1/accountSnapshot",
+                Sapi::FundingAsset => "/sapi/v1/asset/get-funding-asset",
+                Sapi::UserAsset => "/sapi/v3/asset/getUserAsset",
+                Sapi::DustBtc => "/sapi/v1/asset/dust-btc",
+                Sapi::Dust => "/sapi/v1/asset/dust",
+                Sapi::DustLog => "/sapi/v1/asset/dribblet",
+                Sapi::AssetDividend => "/sapi/v1/asset/assetDividend",
                 Sapi::SimpleEarnFlexible => "/sapi/v1/simple-earn/flexible/position",
                 Sapi::SimpleEarnLocked => "/sapi/v1/simple-earn/locked/position",
+                Sapi::SimpleEarnFlexibleList => "/sapi/v1/simple-earn/flexible/list",
+                Sapi::SimpleEarnLockedList => "/sapi/v1/simple-earn/locked/list",
+                Sapi::SimpleEarnFlexibleSubscribe => "/sapi/v1/simple-earn/flexible/subscribe",
+                Sapi::SimpleEarnLockedSubscribe => "/sapi/v1/simple-earn/locked/subscribe",
+                Sapi::SimpleEarnFlexibleRedeem => "/sapi/v1/simple-earn/flexible/redeem",
+                Sapi::SimpleEarnLockedRedeem => "/sapi/v1/simple-earn/locked/redeem",
+                Sapi::SimpleEarnFlexibleSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/subscriptionRecord"
+                }
+                Sapi::SimpleEarnLockedSubscriptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/subscriptionRecord"
+                }
+                Sapi::SimpleEarnFlexibleRedemptionRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/redemptionRecord"
+                }
+                Sapi::SimpleEarnLockedRedemptionRecord => {
+                    "/sapi/v1/simple-earn/locked/history/redemptionRecord"
+                }
+                Sapi::SimpleEarnFlexibleRewardsRecord => {
+                    "/sapi/v1/simple-earn/flexible/history/rewardsRecord"
+                }
+                Sapi::SimpleEarnLockedRewardsRecord => {
+                    "/sapi/v1/simple-earn/locked/history/rewardsRecord"
+                }
+                Sapi::SimpleEarnFlexiblePersonalLeftQuota => {
+                    "/sapi/v1/simple-earn/flexible/personalLeftQuota"
+                }
+                Sapi::SimpleEarnLockedPersonalLeftQuota => {
+                    "/sapi/v1/simple-earn/locked/personalLeftQuota"
+                }
+                Sapi::MarginBorrowRepay => "/sapi/v1/margin/borrow-repay",
+                Sapi::MarginOrder => "/sapi/v1/margin/order",
+                Sapi::MarginOrderOco => "/sapi/v1/margin/order/oco",
+                Sapi::MarginOrderList => "/sapi/v1/margin/orderList",
+                Sapi::MarginOpenOrders => "/sapi/v1/margin/openOrders",
+                Sapi::MarginMaxBorrowable => "/sapi/v1/margin/maxBorrowable",
+                Sapi::MarginMaxTransferable => "/sapi/v1/margin/maxTransferable",
+                Sapi::MarginInterestHistory => "/sapi/v1/margin/interestHistory",
+                Sapi::MarginAccount => "/sapi/v1/margin/account",
+                Sapi::MarginIsolatedAccount => "/sapi/v1/margin/isolated/account",
+                Sapi::MarginUserDataStream => "/sapi/v1/userDataStream",
+                Sapi::SubAccountList => "/sapi/v1/sub-account/list",
+                Sapi::SubAccountCreate => "/sapi/v1/sub-account/virtualSubAccount",
+                Sapi::SubAccountSpotSummary => "/sapi/v1/sub-account/spotSummary",
+                Sapi::SubAccountFuturesSummary => "/sapi/v2/sub-account/futures/accountSummary",
+                Sapi::SubAccountMarginSummary => "/sapi/v1/sub-account/margin/accountSummary",
+                Sapi::SubAccountUniversalTransfer => "/sapi/v1/sub-account/universalTransfer",
+                Sapi::SubAccountFuturesEnable => "/sapi/v1/sub-account/futures/enable",
+                Sapi::SubAccountMarginEnable => "/sapi/v1/sub-account/margin/enable",
+                Sapi::SubAccountIpRestriction => "/sapi/v1/sub-account/subAccountApi/ipRestriction",
+                Sapi::SubAccountIpRestrictionUpdate => {
+                    "/sapi/v2/sub-account/subAccountApi/ipRestriction"
+                }
+                Sapi::SubAccountIpList => "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList",
+                Sapi::ConvertExchangeInfo => "/sapi/v1/convert/exchangeInfo",
+                Sapi::ConvertAssetInfo => "/sapi/v1/convert/assetInfo",
+                Sapi::ConvertGetQuote => "/sapi/v1/convert/getQuote",
+                Sapi::ConvertAcceptQuote => "/sapi/v1/convert/acceptQuote",
+                Sapi::ConvertOrderStatus => "/sapi/v1/convert/orderStatus",
+                Sapi::ConvertLimitPlaceOrder => "/sapi/v1/convert/limit/placeOrder",
+                Sapi::ConvertLimitCancelOrder => "/sapi/v1/convert/limit/cancelOrder",
+                Sapi::ConvertLimitOpenOrders => "/sapi/v1/convert/limit/queryOpenOrders",
+                Sapi::ConvertTradeFlow => "/sapi/v1/convert/tradeFlow",
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping",
@@ -156,6 +411,8 @@ impl From<API> for String {
                 Futures::UserTrades => "/fapi/v1/userTrades",
                 Futures::PositionSide => "/fapi/v1/positionSide/dual",
                 Futures::Order => "/fapi/v1/order",
+                Futures::BatchOrders => "/fapi/v1/batchOrders",
+                Futures::OrderAmendment => "/fapi/v1/orderAmendment",
                 Futures::PositionRisk => "/fapi/v2/positionRisk",
                 Futures::Balance => "/fapi/v2/balance",
                 Futures::OpenInterest => "/fapi/v1/openInterest",
@@ -166,13 +423,103 @@ impl From<API> for String {
                 Futures::TakerlongshortRatio => "/futures/data/takerlongshortRatio",
                 Futures::LvtKlines => "/fapi/v1/lvtKlines",
                 Futures::IndexInfo => "/fapi/v1/indexInfo",
+                Futures::LeverageBracket => "/fapi/v1/leverageBracket",
                 Futures::ChangeInitialLeverage => "/fapi/v1/leverage",
                 Futures::MarginType => "/fapi/v1/marginType",
                 Futures::PositionMargin => "/fapi/v1/positionMargin",
                 Futures::Account => "/fapi/v2/account",
+                Futures::OpenOrder => "/fapi/v1/openOrder",
                 Futures::OpenOrders => "/fapi/v1/openOrders",
                 Futures::UserDataStream => "/fapi/v1/listenKey",
                 Futures::Income => "/fapi/v1/income",
+                Futures::MultiAssetsMargin => "/fapi/v1/multiAssetsMargin",
+                Futures::CommissionRate => "/fapi/v1/commissionRate",
+                Futures::AdlQuantile => "/fapi/v1/adlQuantile",
+                Futures::ForceOrders => "/fapi/v1/forceOrders",
+                Futures::PositionMarginHistory => "/fapi/v1/positionMargin/history",
+                Futures::SymbolConfig => "/fapi/v1/symbolConfig",
+                Futures::AccountConfig => "/fapi/v1/accountConfig",
+                Futures::ApiTradingStatus => "/fapi/v1/apiTradingStatus",
+                Futures::FeeBurn => "/fapi/v1/feeBurn",
+                Futures::CountdownCancelAll => "/fapi/v1/countdownCancelAll",
+                Futures::IncomeAsyn => "/fapi/v1/income/asyn",
+                Futures::IncomeAsynId => "/fapi/v1/income/asyn/id",
+                Futures::OrderAsyn => "/fapi/v1/order/asyn",
+                Futures::OrderAsynId => "/fapi/v1/order/asyn/id",
+                Futures::TradeAsyn => "/fapi/v1/trade/asyn",
+                Futures::TradeAsynId => "/fapi/v1/trade/asyn/id",
+            },
+            API::Delivery(route) => match route {
+                Delivery::Ping => "/dapi/v1/ping",
+                Delivery::Time => "/dapi/v1/time",
+                Delivery::ExchangeInfo => "/dapi/v1/exchangeInfo",
+                Delivery::Depth => "/dapi/v1/depth",
+                Delivery::Trades => "/dapi/v1/trades",
+                Delivery::HistoricalTrades => "/dapi/v1/historicalTrades",
+                Delivery::AggTrades => "/dapi/v1/aggTrades",
+                Delivery::Klines => "/dapi/v1/klines",
+                Delivery::ContinuousKlines => "/dapi/v1/continuousKlines",
+                Delivery::IndexPriceKlines => "/dapi/v1/indexPriceKlines",
+                Delivery::MarkPriceKlines => "/dapi/v1/markPriceKlines",
+                Delivery::PremiumIndex => "/dapi/v1/premiumIndex",
+                Delivery::FundingRate => "/dapi/v1/fundingRate",
+                Delivery::Ticker24hr => "/dapi/v1/ticker/24hr",
+                Delivery::TickerPrice => "/dapi/v1/ticker/price",
+                Delivery::BookTicker => "/dapi/v1/ticker/bookTicker",
+                Delivery::OpenInterest => "/dapi/v1/openInterest",
+                Delivery::Order => "/dapi/v1/order",
+                Delivery::OpenOrders => "/dapi/v1/openOrders",
+                Delivery::AllOpenOrders => "/dapi/v1/allOpenOrders",
+                Delivery::AllOrders => "/dapi/v1/allOrders",
+                Delivery::UserTrades => "/dapi/v1/userTrades",
+                Delivery::PositionRisk => "/dapi/v1/positionRisk",
+                Delivery::Balance => "/dapi/v1/balance",
+                Delivery::Account => "/dapi/v1/account",
+                Delivery::PositionSide => "/dapi/v1/positionSide/dual",
+                Delivery::ChangeInitialLeverage => "/dapi/v1/leverage",
+                Delivery::MarginType => "/dapi/v1/marginType",
+                Delivery::PositionMargin => "/dapi/v1/positionMargin",
+                Delivery::Income => "/dapi/v1/income",
+                Delivery::UserDataStream => "/dapi/v1/listenKey",
+            },
+            API::Options(route) => match route {
+                Options::Ping => "/eapi/v1/ping",
+                Options::Time => "/eapi/v1/time",
+                Options::ExchangeInfo => "/eapi/v1/exchangeInfo",
+                Options::Depth => "/eapi/v1/depth",
+                Options::Klines => "/eapi/v1/klines",
+                Options::Mark => "/eapi/v1/mark",
+                Options::Ticker => "/eapi/v1/ticker",
+                Options::ExerciseHistory => "/eapi/v1/exerciseHistory",
+                Options::Order => "/eapi/v1/order",
+                Options::OpenOrders => "/eapi/v1/openOrders",
+                Options::AllOpenOrders => "/eapi/v1/allOpenOrders",
+                Options::HistoryOrders => "/eapi/v1/historyOrders",
+                Options::MarginAccount => "/eapi/v1/marginAccount",
+                Options::Position => "/eapi/v1/position",
+                Options::UserDataStream => "/eapi/v1/listenKey",
+            },
+            API::PortfolioMargin(route) => match route {
+                Papi::Ping => "/papi/v1/ping",
+                Papi::UmOrder => "/papi/v1/um/order",
+                Papi::UmConditionalOrder => "/papi/v1/um/conditional/order",
+                Papi::UmOpenOrders => "/papi/v1/um/openOrders",
+                Papi::UmAllOpenOrders => "/papi/v1/um/allOpenOrders",
+                Papi::UmPositionRisk => "/papi/v1/um/positionRisk",
+                Papi::UmLeverage => "/papi/v1/um/leverage",
+                Papi::CmOrder => "/papi/v1/cm/order",
+                Papi::CmConditionalOrder => "/papi/v1/cm/conditional/order",
+                Papi::CmOpenOrders => "/papi/v1/cm/openOrders",
+                Papi::CmAllOpenOrders => "/papi/v1/cm/allOpenOrders",
+                Papi::CmPositionRisk => "/papi/v1/cm/positionRisk",
+                Papi::CmLeverage => "/papi/v1/cm/leverage",
+                Papi::MarginLoan => "/papi/v1/marginLoan",
+                Papi::RepayLoan => "/papi/v1/repayLoan",
+                Papi::Balance => "/papi/v1/balance",
+                Papi::Account => "/papi/v1/account",
+                Papi::AutoCollection => "/papi/v1/auto-collection",
+                Papi::AssetCollection => "/papi/v1/asset-collection",
+                Papi::UserDataStream => "/papi/v1/listenKey",
             },
         })
     }
@@ -229,6 +576,51 @@ impl Binance for Savings {
     }
 }
 
+impl Binance for Convert {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Convert {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Convert {
+        Convert {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for SubAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> SubAccount {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> SubAccount {
+        SubAccount {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for Margin {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Margin {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Margin {
+        Margin {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
 impl Binance for Market {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Market {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -337,3 +729,184 @@ impl Binance for FuturesUserStream {
         }
     }
 }
+
+// *****************************************************
+//              Binance COIN-M Futures API
+// *****************************************************
+
+impl Binance for DeliveryGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> DeliveryGeneral {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> DeliveryGeneral {
+        DeliveryGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+        }
+    }
+}
+
+impl Binance for DeliveryMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> DeliveryMarket {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> DeliveryMarket {
+        DeliveryMarket {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for DeliveryAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for DeliveryUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> DeliveryUserStream {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> DeliveryUserStream {
+        DeliveryUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance European Options API
+// *****************************************************
+
+impl Binance for OptionsGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsGeneral {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsGeneral {
+        OptionsGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+        }
+    }
+}
+
+impl Binance for OptionsMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsMarket {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsMarket {
+        OptionsMarket {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsUserStream {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsUserStream {
+        OptionsUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance Portfolio Margin API
+// *****************************************************
+
+impl Binance for PortfolioMargin {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.portfolio_margin_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
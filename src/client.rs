@@ -53,6 +53,17 @@ impl Client {
         self.handler(response)
     }
 
+    pub fn put_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
+        let url = self.sign_request(endpoint, Some(request));
+        let client = &self.inner_client;
+        let response = client
+            .put(url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
     pub fn delete_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
@@ -120,6 +131,21 @@ impl Client {
         self.handler(response)
     }
 
+    // Plain GET of an absolute URL, such as a download link
+    pub fn download(&self, url: &str) -> Result<String> {
+        let response = self.inner_client.get(url).send()?;
+        let status = response.status();
+        let text = response.text()?;
+
+        match status {
+            StatusCode::OK => Ok(text),
+            s => Err(SdkError::Other(format!(
+                "Unexpected status code: {} while downloading {}",
+                s, url
+            ))),
+        }
+    }
+
     // Request must be signed
     fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
         if let Some(request) = request {
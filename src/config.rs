@@ -1,3 +1,6 @@
+use url::Url;
+use crate::errors::{Result, SdkError};
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
@@ -6,6 +9,15 @@ pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub delivery_rest_api_endpoint: String,
+    pub delivery_ws_endpoint: String,
+
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
+    pub portfolio_margin_rest_api_endpoint: String,
+    pub portfolio_margin_ws_endpoint: String,
+
     pub recv_window: u64,
 }
 
@@ -18,18 +30,37 @@ impl Default for Config {
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
 
+            delivery_rest_api_endpoint: "https://dapi.binance.com".into(),
+            delivery_ws_endpoint: "wss://dstream.binance.com/ws".into(),
+
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions/ws".into(),
+
+            portfolio_margin_rest_api_endpoint: "https://papi.binance.com".into(),
+            portfolio_margin_ws_endpoint: "wss://fstream.binance.com/pm/ws".into(),
+
             recv_window: 5000,
         }
     }
 }
 
 impl Config {
+    /// Spot, USDⓈ-M and COIN-M futures testnet endpoints.
+    ///
+    /// Options and Portfolio Margin have no testnet, so their endpoints are left empty:
+    /// requests to them fail instead of reaching production, and `validate` reports them.
     pub fn testnet() -> Self {
         Self::default()
             .set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision/ws")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
-            .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_futures_ws_endpoint("wss://fstream.binancefuture.com/ws")
+            .set_delivery_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_delivery_ws_endpoint("wss://dstream.binancefuture.com/ws")
+            .set_options_rest_api_endpoint("")
+            .set_options_ws_endpoint("")
+            .set_portfolio_margin_rest_api_endpoint("")
+            .set_portfolio_margin_ws_endpoint("")
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -53,8 +84,118 @@ impl Config {
         self
     }
 
+    pub fn set_delivery_rest_api_endpoint<T: Into<String>>(
+        mut self, delivery_rest_api_endpoint: T,
+    ) -> Self {
+        self.delivery_rest_api_endpoint = delivery_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_delivery_ws_endpoint<T: Into<String>>(mut self, delivery_ws_endpoint: T) -> Self {
+        self.delivery_ws_endpoint = delivery_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self, options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_portfolio_margin_rest_api_endpoint<T: Into<String>>(
+        mut self, portfolio_margin_rest_api_endpoint: T,
+    ) -> Self {
+        self.portfolio_margin_rest_api_endpoint = portfolio_margin_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_portfolio_margin_ws_endpoint<T: Into<String>>(
+        mut self, portfolio_margin_ws_endpoint: T,
+    ) -> Self {
+        self.portfolio_margin_ws_endpoint = portfolio_margin_ws_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
     }
+
+    /// Checks that the REST endpoints are http(s) urls and the websocket endpoints ws(s) urls
+    /// ending with `/ws`.
+    pub fn validate(&self) -> Result<()> {
+        for endpoint in [
+            &self.rest_api_endpoint,
+            &self.futures_rest_api_endpoint,
+            &self.delivery_rest_api_endpoint,
+            &self.options_rest_api_endpoint,
+            &self.portfolio_margin_rest_api_endpoint,
+        ] {
+            check_endpoint(endpoint, &["http", "https"])?;
+        }
+        for endpoint in [
+            &self.ws_endpoint,
+            &self.futures_ws_endpoint,
+            &self.delivery_ws_endpoint,
+            &self.options_ws_endpoint,
+            &self.portfolio_margin_ws_endpoint,
+        ] {
+            combined_stream_endpoint(endpoint)?;
+        }
+        Ok(())
+    }
+}
+
+fn check_endpoint(endpoint: &str, schemes: &[&str]) -> Result<()> {
+    if endpoint.is_empty() {
+        return Err(SdkError::Other(
+            "Endpoint not set, as on testnet for options and portfolio margin".to_string(),
+        ));
+    }
+    let url = Url::parse(endpoint)
+        .map_err(|e| SdkError::Other(format!("Invalid endpoint {}: {}", endpoint, e)))?;
+    if !schemes.contains(&url.scheme()) {
+        return Err(SdkError::Other(format!(
+            "Invalid endpoint {}: expected a {} url",
+            endpoint,
+            schemes.join(" or ")
+        )));
+    }
+    Ok(())
+}
+
+// Url of the raw stream `subscription` on a websocket endpoint such as `wss://stream.binance.com/ws`
+pub(crate) fn raw_stream_url(ws_endpoint: &str, subscription: &str) -> Result<String> {
+    check_endpoint(ws_endpoint, &["ws", "wss"])?;
+    Ok(format!(
+        "{}/{}",
+        ws_endpoint.trim_end_matches('/'),
+        subscription
+    ))
+}
+
+// Url of the combined `streams` on a websocket endpoint, served next to it at `/stream`
+pub(crate) fn combined_stream_url(ws_endpoint: &str, streams: &[String]) -> Result<String> {
+    Ok(format!(
+        "{}?streams={}",
+        combined_stream_endpoint(ws_endpoint)?,
+        streams.join("/")
+    ))
+}
+
+fn combined_stream_endpoint(ws_endpoint: &str) -> Result<String> {
+    check_endpoint(ws_endpoint, &["ws", "wss"])?;
+    match ws_endpoint.trim_end_matches('/').strip_suffix("/ws") {
+        Some(base) => Ok(format!("{}/stream", base)),
+        None => Err(SdkError::Other(format!(
+            "Invalid websocket endpoint {}: expected it to end with /ws",
+            ws_endpoint
+        ))),
+    }
 }
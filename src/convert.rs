@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::util::{build_request, build_signed_request};
+use crate::model::{
+    ConvertAcceptedQuote, ConvertAssetPrecision, ConvertLimitOrder, ConvertOrder, ConvertOrderList,
+    ConvertPair, ConvertQuote, ConvertTradeFlow,
+};
+use crate::account::OrderSide;
+use crate::client::Client;
+use crate::errors::{Result, SdkError};
+use crate::api::API;
+use crate::api::Sapi;
+
+// Longest time range of the trade flow
+const TRADE_FLOW_MAX_RANGE: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// Error code of Binance's rejection of an expired quote.
+pub const QUOTE_EXPIRED_CODE: i32 = 345124;
+
+/// Binance Convert: swap assets at a quoted price instead of trading on the order book.
+///
+/// A quote must be accepted before its `valid_timestamp`; `accept_quote` returns
+/// `SdkError::QuoteExpired` for a stale quote instead of sending it to Binance.
+#[derive(Clone)]
+pub struct Convert {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Wallet the assets are taken from.
+pub enum ConvertWalletType {
+    Spot,
+    Funding,
+    /// Spot first, then funding.
+    SpotFunding,
+}
+
+impl Display for ConvertWalletType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::Funding => write!(f, "FUNDING"),
+            Self::SpotFunding => write!(f, "SPOT_FUNDING"),
+        }
+    }
+}
+
+/// How long a quote stays valid.
+pub enum QuoteValidTime {
+    TenSeconds,
+    ThirtySeconds,
+    OneMinute,
+    TwoMinutes,
+}
+
+impl Display for QuoteValidTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TenSeconds => write!(f, "10s"),
+            Self::ThirtySeconds => write!(f, "30s"),
+            Self::OneMinute => write!(f, "1m"),
+            Self::TwoMinutes => write!(f, "2m"),
+        }
+    }
+}
+
+/// How long a limit convert order stays open.
+pub enum LimitOrderExpiry {
+    OneDay,
+    ThreeDays,
+    SevenDays,
+    ThirtyDays,
+}
+
+impl Display for LimitOrderExpiry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OneDay => write!(f, "1_D"),
+            Self::ThreeDays => write!(f, "3_D"),
+            Self::SevenDays => write!(f, "7_D"),
+            Self::ThirtyDays => write!(f, "30_D"),
+        }
+    }
+}
+
+/// Quote for converting `from_asset` to `to_asset`; exactly one of `from_amount`
+/// and `to_amount` must be set.
+pub struct ConvertQuoteRequest {
+    pub from_asset: String,
+    pub to_asset: String,
+    pub from_amount: Option<f64>,
+    pub to_amount: Option<f64>,
+    pub wallet_type: Option<ConvertWalletType>,
+    pub valid_time: Option<QuoteValidTime>,
+}
+
+impl ConvertQuoteRequest {
+    pub fn new<S: Into<String>>(from_asset: S, to_asset: S) -> Self {
+        ConvertQuoteRequest {
+            from_asset: from_asset.into(),
+            to_asset: to_asset.into(),
+            from_amount: None,
+            to_amount: None,
+            wallet_type: None,
+            valid_time: None,
+        }
+    }
+}
+
+/// Limit order to buy or sell `base_asset` for `quote_asset` at `limit_price`; exactly
+/// one of `base_amount` and `quote_amount` must be set.
+pub struct LimitConvertOrderRequest {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub limit_price: f64,
+    pub base_amount: Option<f64>,
+    pub quote_amount: Option<f64>,
+    pub side: OrderSide,
+    pub wallet_type: Option<ConvertWalletType>,
+    pub expired_type: LimitOrderExpiry,
+}
+
+impl Convert {
+    /// List the convert pairs, optionally restricted to a source and/or target asset.
+    pub fn exchange_info(
+        &self, from_asset: Option<String>, to_asset: Option<String>,
+    ) -> Result<Vec<ConvertPair>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(from_asset) = from_asset {
+            parameters.insert("fromAsset".into(), from_asset);
+        }
+        if let Some(to_asset) = to_asset {
+            parameters.insert("toAsset".into(), to_asset);
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Savings(Sapi::ConvertExchangeInfo), Some(request))
+    }
+
+    /// Precision of the amounts of each convertible asset.
+    pub fn asset_info(&self) -> Result<Vec<ConvertAssetPrecision>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ConvertAssetInfo), Some(request))
+    }
+
+    /// Request a quote, to be accepted with `accept_quote` before it expires.
+    pub fn get_quote(&self, quote_request: ConvertQuoteRequest) -> Result<ConvertQuote> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("fromAsset".into(), quote_request.from_asset);
+        parameters.insert("toAsset".into(), quote_request.to_asset);
+        match (quote_request.from_amount, quote_request.to_amount) {
+            (Some(from_amount), None) => {
+                parameters.insert("fromAmount".into(), from_amount.to_string());
+            }
+            (None, Some(to_amount)) => {
+                parameters.insert("toAmount".into(), to_amount.to_string());
+            }
+            _ => {
+                return Err(SdkError::Other(
+                    "A convert quote needs exactly one of from_amount and to_amount".into(),
+                ));
+            }
+        }
+        if let Some(wallet_type) = quote_request.wallet_type {
+            parameters.insert("walletType".into(), wallet_type.to_string());
+        }
+        if let Some(valid_time) = quote_request.valid_time {
+            parameters.insert("validTime".into(), valid_time.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertGetQuote), request)
+    }
+
+    /// Accept a quote returned by `get_quote`.
+    ///
+    /// Returns `SdkError::QuoteExpired` without calling Binance if the quote is past its
+    /// `valid_timestamp`, and when Binance rejects the quote with `QUOTE_EXPIRED_CODE`.
+    /// Other rejections are returned as `SdkError::BinanceError`.
+    pub fn accept_quote(&self, quote: &ConvertQuote) -> Result<ConvertAcceptedQuote> {
+        let quote_expired = || SdkError::QuoteExpired {
+            quote_id: quote.quote_id.clone(),
+            valid_timestamp: quote.valid_timestamp,
+        };
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+        if now >= quote.valid_timestamp {
+            return Err(quote_expired());
+        }
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("quoteId".into(), quote.quote_id.clone());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertAcceptQuote), request)
+            .map_err(|error| match error {
+                SdkError::BinanceError(ref content) if content.code == QUOTE_EXPIRED_CODE => {
+                    quote_expired()
+                }
+                error => error,
+            })
+    }
+
+    /// Status of a convert order, by the order id returned by `accept_quote`.
+    pub fn order_status(&self, order_id: u64) -> Result<ConvertOrder> {
+        self.get_order_status("orderId", order_id.to_string())
+    }
+
+    /// Status of a convert order, by the id of its quote.
+    pub fn order_status_by_quote<S>(&self, quote_id: S) -> Result<ConvertOrder>
+    where
+        S: Into<String>,
+    {
+        self.get_order_status("quoteId", quote_id.into())
+    }
+
+    /// Place a limit convert order, filled once the market reaches `limit_price`.
+    pub fn limit_order(
+        &self, order_request: LimitConvertOrderRequest,
+    ) -> Result<ConvertLimitOrder> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("baseAsset".into(), order_request.base_asset);
+        parameters.insert("quoteAsset".into(), order_request.quote_asset);
+        parameters.insert("limitPrice".into(), order_request.limit_price.to_string());
+        match (order_request.base_amount, order_request.quote_amount) {
+            (Some(base_amount), None) => {
+                parameters.insert("baseAmount".into(), base_amount.to_string());
+            }
+            (None, Some(quote_amount)) => {
+                parameters.insert("quoteAmount".into(), quote_amount.to_string());
+            }
+            _ => {
+                return Err(SdkError::Other(
+                    "A limit convert order needs exactly one of base_amount and quote_amount"
+                        .into(),
+                ));
+            }
+        }
+        parameters.insert("side".into(), order_request.side.to_string());
+        if let Some(wallet_type) = order_request.wallet_type {
+            parameters.insert("walletType".into(), wallet_type.to_string());
+        }
+        parameters.insert("expiredType".into(), order_request.expired_type.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertLimitPlaceOrder), request)
+    }
+
+    /// Cancel an open limit convert order.
+    pub fn cancel_limit_order(&self, order_id: u64) -> Result<ConvertLimitOrder> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::ConvertLimitCancelOrder), request)
+    }
+
+    /// Open limit convert orders.
+    pub fn open_limit_orders(&self) -> Result<Vec<ConvertOrder>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .post_signed::<ConvertOrderList>(API::Savings(Sapi::ConvertLimitOpenOrders), request)
+            .map(|orders| orders.list)
+    }
+
+    /// Convert trades between `start_time` and `end_time`, at most 30 days apart.
+    ///
+    /// If `more_data` is set, fetch the rest with a later `start_time`.
+    pub fn trade_flow(
+        &self, start_time: u64, end_time: u64, limit: Option<u32>,
+    ) -> Result<ConvertTradeFlow> {
+        if end_time < start_time || end_time - start_time > TRADE_FLOW_MAX_RANGE {
+            return Err(SdkError::Other(
+                "The convert trade flow spans at most 30 days".into(),
+            ));
+        }
+        let mut parameters = BTreeMap::new();
+        parameters.insert("startTime".into(), start_time.to_string());
+        parameters.insert("endTime".into(), end_time.to_string());
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ConvertTradeFlow), Some(request))
+    }
+
+    fn get_order_status(&self, id_name: &str, id: String) -> Result<ConvertOrder> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert(id_name.into(), id);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ConvertOrderStatus), Some(request))
+    }
+}
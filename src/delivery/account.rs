@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use crate::util::build_signed_request;
+use crate::errors::Result;
+use crate::client::Client;
+use crate::api::{API, Delivery};
+use crate::model::Empty;
+use crate::account::OrderSide;
+use crate::futures::account::{
+    build_history, build_income_request, build_leverage, build_margin_type, build_order,
+    build_order_id, build_position_margin, build_position_mode, CustomOrderRequest, IncomeRequest,
+    OrderType, TimeInForce,
+};
+
+use super::model::{
+    AccountBalance, AccountInformation, ChangeLeverageResponse, Income, Order, PositionRisk,
+    TradeHistory,
+};
+
+/// Account endpoints of the COIN-M futures.
+///
+/// Quantities are expressed in contracts, see `Symbol::contract_size`.
+#[derive(Clone)]
+pub struct DeliveryAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl DeliveryAccount {
+    pub fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(Self::limit_order(
+            symbol.into(),
+            OrderSide::Buy,
+            qty.into(),
+            price,
+            time_in_force,
+        )?)
+    }
+
+    pub fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(Self::limit_order(
+            symbol.into(),
+            OrderSide::Sell,
+            qty.into(),
+            price,
+            time_in_force,
+        )?)
+    }
+
+    // Place a MARKET order - BUY
+    pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(Self::market_order(
+            symbol.into(),
+            OrderSide::Buy,
+            qty.into(),
+        )?)
+    }
+
+    // Place a MARKET order - SELL
+    pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(Self::market_order(
+            symbol.into(),
+            OrderSide::Sell,
+            qty.into(),
+        )?)
+    }
+
+    // Custom order for for professional traders
+    pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Order> {
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Delivery(Delivery::Order), request)
+    }
+
+    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_order_id(symbol.into(), "orderId", order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::Order), Some(request))
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_order_id(symbol.into(), "orderId", order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Delivery(Delivery::Order), Some(request))
+    }
+
+    pub fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_order_id(symbol.into(), "origClientOrderId", orig_client_order_id);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Delivery(Delivery::Order), Some(request))
+    }
+
+    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Delivery(Delivery::AllOpenOrders), Some(request))
+            .map(|_| ())
+    }
+
+    pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::OpenOrders), Some(request))
+    }
+
+    pub fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let parameters = build_history(
+            symbol.into(),
+            ("orderId", order_id.into()),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::AllOrders), Some(request))
+    }
+
+    pub fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let parameters = build_history(
+            symbol.into(),
+            ("fromId", from_id.into()),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::UserTrades), Some(request))
+    }
+
+    // Positions of ONE pair ("BTCUSD", ...), or of all pairs if `None`
+    pub fn position_information<S>(&self, pair: S) -> Result<Vec<PositionRisk>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(pair) = pair.into() {
+            parameters.insert("pair".into(), pair);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::PositionRisk), Some(request))
+    }
+
+    pub fn account_information(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::Account), Some(request))
+    }
+
+    pub fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::Balance), Some(request))
+    }
+
+    pub fn change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_leverage(symbol.into(), leverage);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Delivery(Delivery::ChangeInitialLeverage), request)
+    }
+
+    pub fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_margin_type(symbol.into(), isolated);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Delivery(Delivery::MarginType), request)
+            .map(|_| ())
+    }
+
+    pub fn change_position_margin<S>(
+        &self, symbol: S, amount: f64, is_adding_margin: bool,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_position_margin(symbol.into(), amount, is_adding_margin);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Delivery(Delivery::PositionMargin), request)
+            .map(|_| ())
+    }
+
+    pub fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let parameters = build_position_mode(dual_side_position);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Delivery(Delivery::PositionSide), request)
+            .map(|_| ())
+    }
+
+    pub fn get_income(&self, income_request: IncomeRequest) -> Result<Vec<Income>> {
+        let parameters = build_income_request(income_request)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Delivery(Delivery::Income), Some(request))
+    }
+
+    fn limit_order(
+        symbol: String, side: OrderSide, qty: f64, price: f64, time_in_force: TimeInForce,
+    ) -> Result<CustomOrderRequest> {
+        CustomOrderRequest::builder(symbol, side, OrderType::Limit)
+            .set_time_in_force(time_in_force)
+            .set_qty(qty)
+            .set_price(price)
+            .build()
+    }
+
+    fn market_order(symbol: String, side: OrderSide, qty: f64) -> Result<CustomOrderRequest> {
+        CustomOrderRequest::builder(symbol, side, OrderType::Market)
+            .set_qty(qty)
+            .build()
+    }
+}
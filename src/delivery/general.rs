@@ -0,0 +1,39 @@
+use crate::delivery::model::{ExchangeInformation, ServerTime, Symbol};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::futures::general::{find_symbol, ping};
+use crate::api::API;
+use crate::api::Delivery;
+
+#[derive(Clone)]
+pub struct DeliveryGeneral {
+    pub client: Client,
+}
+
+impl DeliveryGeneral {
+    // Test connectivity
+    pub fn ping(&self) -> Result<String> {
+        ping(&self.client, API::Delivery(Delivery::Ping))
+    }
+
+    // Check server time
+    pub fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::Delivery(Delivery::Time), None)
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and symbol information
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get(API::Delivery(Delivery::ExchangeInfo), None)
+    }
+
+    // Get Symbol information
+    pub fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        find_symbol(self.exchange_info()?.symbols, symbol.into(), |item| {
+            &item.symbol
+        })
+    }
+}
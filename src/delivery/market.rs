@@ -0,0 +1,264 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [x] `Old Trades Lookup (MARKET_DATA)`
+- [x] `Compressed/Aggregate Trades List`
+- [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price and Mark Price`
+- [x] `Get Funding Rate History of Perpetual Futures`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker`
+- [x] `Symbol Order Book Ticker`
+- [x] `Open Interest`
+*/
+
+use crate::util::build_request;
+use crate::delivery::model::{
+    AggTrades, BookTicker, FundingRate, KlineSummaries, MarkPrice, OpenInterest, OrderBook,
+    PriceStats, SymbolPrice, Trades,
+};
+use crate::futures::account::ContractType;
+use crate::futures::market::{
+    get_agg_trades, get_depth, get_for_symbol, get_historical_trades, get_kline_summaries,
+    insert_range,
+};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Delivery;
+
+#[derive(Clone)]
+pub struct DeliveryMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl DeliveryMarket {
+    // Order book (Default 500; max 1000)
+    pub fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        get_depth(
+            &self.client,
+            API::Delivery(Delivery::Depth),
+            symbol.into(),
+            None,
+        )
+    }
+
+    // Order book at a custom depth. Currently supported values
+    // are 5, 10, 20, 50, 100, 500, 1000
+    pub fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        get_depth(
+            &self.client,
+            API::Delivery(Delivery::Depth),
+            symbol.into(),
+            Some(depth),
+        )
+    }
+
+    pub fn get_trades<S>(&self, symbol: S) -> Result<Trades>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(&self.client, API::Delivery(Delivery::Trades), symbol.into())
+    }
+
+    pub fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Trades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        get_historical_trades(
+            &self.client,
+            API::Delivery(Delivery::HistoricalTrades),
+            self.recv_window,
+            symbol.into(),
+            from_id.into(),
+            limit.into(),
+        )
+    }
+
+    pub fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<AggTrades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        get_agg_trades(
+            &self.client,
+            API::Delivery(Delivery::AggTrades),
+            symbol.into(),
+            from_id.into(),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        )
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        get_kline_summaries(
+            &self.client,
+            API::Delivery(Delivery::Klines),
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+    }
+
+    // Klines of a continuous contract for a pair ("BTCUSD", ...)
+    pub fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+        get_kline_summaries(
+            &self.client,
+            API::Delivery(Delivery::ContinuousKlines),
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+    }
+
+    // Mark and index price of ONE symbol
+    pub fn get_mark_price<S>(&self, symbol: S) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(
+            &self.client,
+            API::Delivery(Delivery::PremiumIndex),
+            symbol.into(),
+        )
+    }
+
+    // Mark and index price of all symbols
+    pub fn get_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get(API::Delivery(Delivery::PremiumIndex), None)
+    }
+
+    pub fn get_funding_rate<S1, S2, S3, S4>(
+        &self, symbol: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        insert_range(
+            &mut parameters,
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Delivery(Delivery::FundingRate), Some(request))
+    }
+
+    // 24hr ticker price change statistics
+    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(
+            &self.client,
+            API::Delivery(Delivery::Ticker24hr),
+            symbol.into(),
+        )
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get(API::Delivery(Delivery::Ticker24hr), None)
+    }
+
+    // Latest price for ONE symbol.
+    pub fn get_price<S>(&self, symbol: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(
+            &self.client,
+            API::Delivery(Delivery::TickerPrice),
+            symbol.into(),
+        )
+    }
+
+    // Latest price for all symbols.
+    pub fn get_all_prices(&self) -> Result<Vec<SymbolPrice>> {
+        self.client.get(API::Delivery(Delivery::TickerPrice), None)
+    }
+
+    // -> Best price/qty on the order book for ONE symbol
+    pub fn get_book_ticker<S>(&self, symbol: S) -> Result<Vec<BookTicker>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(
+            &self.client,
+            API::Delivery(Delivery::BookTicker),
+            symbol.into(),
+        )
+    }
+
+    // -> Best price/qty on the order book for ALL symbols.
+    pub fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
+        self.client.get(API::Delivery(Delivery::BookTicker), None)
+    }
+
+    pub fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(
+            &self.client,
+            API::Delivery(Delivery::OpenInterest),
+            symbol.into(),
+        )
+    }
+}
@@ -0,0 +1,11 @@
+//! COIN-M (delivery) futures, served from `dapi.binance.com`.
+//!
+//! Orders are built with the same `CustomOrderRequest` as the USDⓈ-M futures,
+//! and the market data models that are identical on both APIs are re-exported
+//! from `futures::model`.
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod model;
+pub mod userstream;
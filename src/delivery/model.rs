@@ -0,0 +1,352 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+
+pub use crate::futures::model::{
+    AggTrades, Asks, Bids, Filters, Income, KlineSummaries, KlineSummary, OrderBook, RateLimit,
+    ServerTime, Trades,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<String>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    /// Value of one contract, in quote asset (e.g. 100 USD for BTCUSD).
+    #[serde(with = "string_or_float")]
+    pub contract_size: f64,
+    pub margin_asset: String,
+    pub maint_margin_percent: String,
+    pub required_margin_percent: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub filters: Vec<Filters>,
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl Symbol {
+    /// Notional of `contracts` contracts in the quote asset.
+    pub fn notional_in_quote(&self, contracts: f64) -> f64 {
+        contracts * self.contract_size
+    }
+
+    /// Notional of `contracts` contracts in the base (margin) asset at the given price.
+    pub fn notional_in_base(&self, contracts: f64, price: f64) -> f64 {
+        contracts * self.contract_size / price
+    }
+
+    /// Number of whole contracts worth at most `base_qty` of the base asset at the given price.
+    pub fn contracts_for_base(&self, base_qty: f64, price: f64) -> f64 {
+        (base_qty * price / self.contract_size).floor()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    /// Volume in contracts.
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    /// Volume in base asset.
+    #[serde(with = "string_or_float")]
+    pub base_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_price: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: f64,
+    /// Empty for delivery contracts.
+    #[serde(default, with = "string_or_float_opt")]
+    pub last_funding_rate: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest_rate: Option<f64>,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    pub funding_time: u64,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// Open interest in contracts.
+    #[serde(with = "string_or_float")]
+    pub open_interest: f64,
+    pub contract_type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: f64,
+    /// Executed amount in base asset.
+    #[serde(with = "string_or_float")]
+    pub cum_base: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: f64,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub orig_type: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<f64>,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeHistory {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// Quantity in contracts.
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: f64,
+    pub margin_asset: String,
+    /// Quantity in base asset.
+    #[serde(with = "string_or_float")]
+    pub base_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: String,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    pub leverage: String,
+    /// Maximum quantity of base asset at the current leverage.
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: f64,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: String,
+    /// Notional of the position in base asset.
+    #[serde(with = "string_or_float")]
+    pub notional_value: f64,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    pub leverage: String,
+    pub isolated: bool,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<DeliveryAsset>,
+    pub positions: Vec<DeliveryPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub symbol: String,
+}
@@ -0,0 +1,35 @@
+use crate::model::{Success, UserDataStream};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::futures::userstream::{close, keep_alive, start};
+use crate::api::API;
+use crate::api::Delivery;
+
+#[derive(Clone)]
+pub struct DeliveryUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl DeliveryUserStream {
+    // User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        start(&self.client, API::Delivery(Delivery::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        keep_alive(
+            &self.client,
+            API::Delivery(Delivery::UserDataStream),
+            listen_key,
+        )
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        close(
+            &self.client,
+            API::Delivery(Delivery::UserDataStream),
+            listen_key,
+        )
+    }
+}
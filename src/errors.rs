@@ -8,6 +8,12 @@ pub enum SdkError {
     #[error("{0}")]
     BinanceError(#[from] BinanceContentError),
 
+    #[error("Quote {quote_id} expired at {valid_timestamp}")]
+    QuoteExpired {
+        quote_id: String,
+        valid_timestamp: u64,
+    },
+
     #[error("{name} at {index} is missing")]
     KlineValueMissingError { name: String, index: usize },
 
@@ -34,6 +40,9 @@ pub enum SdkError {
     #[error("JSON error")]
     Json(#[from] serde_json::Error),
 
+    #[error("CSV error")]
+    Csv(#[from] csv::Error),
+
     #[error("Timestamp error")]
     TimestampError(#[from] std::time::SystemTimeError),
 
@@ -62,6 +71,6 @@ impl From<String> for SdkError {
 #[derive(Error, Debug, Clone, Deserialize)]
 #[error("Binance content error: {msg} (code: {code})")]
 pub struct BinanceContentError {
-    pub code: i16,
+    pub code: i32,
     pub msg: String,
 }
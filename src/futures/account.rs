@@ -1,24 +1,35 @@
 use std::collections::BTreeMap;
 use std::fmt::Display;
-use crate::util::build_signed_request;
-use crate::errors::Result;
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::util::{build_signed_request, encode_param};
+use crate::errors::{Result, SdkError};
 use crate::client::Client;
 use crate::api::{API, Futures};
 use crate::model::Empty;
 use crate::account::OrderSide;
-use crate::futures::model::{Order, TradeHistory};
+use crate::futures::model::{Income, Order, TradeHistory};
+use crate::futures::income::{IncomeHistory, IncomeReport};
+use crate::futures::download::{parse_income_csv, parse_order_csv, parse_trade_csv};
+use serde::{Deserialize, Serialize};
 
 use super::model::{
     ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
-    AccountInformation,
+    AccountInformation, BatchResponse, BatchResult, OrderAmendment, PositionMode, MultiAssetsMode,
+    CommissionRate, AdlQuantile, AdlQuantileResponse, PositionMarginChange, SymbolConfig,
+    AccountConfig, ApiTradingStatus, FeeBurnStatus, CountdownCancelAll, DownloadId, DownloadLink,
 };
 
+const MAX_BATCH_ORDERS: usize = 5;
+const MAX_BATCH_CANCELS: usize = 10;
+
 #[derive(Clone)]
 pub struct FuturesAccount {
     pub client: Client,
     pub recv_window: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ContractType {
     Perpetual,
     CurrentMonth,
@@ -93,12 +104,41 @@ impl Display for WorkingType {
     }
 }
 
+pub enum PriceMatch {
+    Opponent,
+    Opponent5,
+    Opponent10,
+    Opponent20,
+    Queue,
+    Queue5,
+    Queue10,
+    Queue20,
+    None,
+}
+
+impl Display for PriceMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Opponent => write!(f, "OPPONENT"),
+            Self::Opponent5 => write!(f, "OPPONENT_5"),
+            Self::Opponent10 => write!(f, "OPPONENT_10"),
+            Self::Opponent20 => write!(f, "OPPONENT_20"),
+            Self::Queue => write!(f, "QUEUE"),
+            Self::Queue5 => write!(f, "QUEUE_5"),
+            Self::Queue10 => write!(f, "QUEUE_10"),
+            Self::Queue20 => write!(f, "QUEUE_20"),
+            Self::None => write!(f, "NONE"),
+        }
+    }
+}
+
 #[allow(clippy::all)]
 pub enum TimeInForce {
     GTC,
     IOC,
     FOK,
     GTX,
+    GTD,
 }
 
 impl Display for TimeInForce {
@@ -108,11 +148,58 @@ impl Display for TimeInForce {
             Self::IOC => write!(f, "IOC"),
             Self::FOK => write!(f, "FOK"),
             Self::GTX => write!(f, "GTX"),
+            Self::GTD => write!(f, "GTD"),
+        }
+    }
+}
+
+pub enum AutoCloseType {
+    Liquidation,
+    Adl,
+}
+
+impl Display for AutoCloseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Liquidation => write!(f, "LIQUIDATION"),
+            Self::Adl => write!(f, "ADL"),
+        }
+    }
+}
+
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+}
+
+impl Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::ExpireTaker => write!(f, "EXPIRE_TAKER"),
+            Self::ExpireMaker => write!(f, "EXPIRE_MAKER"),
+            Self::ExpireBoth => write!(f, "EXPIRE_BOTH"),
+        }
+    }
+}
+
+pub enum NewOrderResponseType {
+    Ack,
+    Result,
+}
+
+impl Display for NewOrderResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ack => write!(f, "ACK"),
+            Self::Result => write!(f, "RESULT"),
         }
     }
 }
 
-struct OrderRequest {
+pub struct CustomOrderRequest {
     pub symbol: String,
     pub side: OrderSide,
     pub position_side: Option<PositionSide>,
@@ -126,24 +213,150 @@ struct OrderRequest {
     pub activation_price: Option<f64>,
     pub callback_rate: Option<f64>,
     pub working_type: Option<WorkingType>,
-    pub price_protect: Option<f64>,
+    pub price_protect: Option<bool>,
+    pub new_client_order_id: Option<String>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub price_match: Option<PriceMatch>,
+    /// Cancellation time in milliseconds, required with `TimeInForce::GTD`.
+    pub good_till_date: Option<u64>,
+    pub new_order_resp_type: Option<NewOrderResponseType>,
 }
 
-pub struct CustomOrderRequest {
+impl CustomOrderRequest {
+    pub fn builder<S: Into<String>>(
+        symbol: S, side: OrderSide, order_type: OrderType,
+    ) -> CustomOrderRequestBuilder {
+        CustomOrderRequestBuilder {
+            request: CustomOrderRequest {
+                symbol: symbol.into(),
+                side,
+                position_side: None,
+                order_type,
+                time_in_force: None,
+                qty: None,
+                reduce_only: None,
+                price: None,
+                stop_price: None,
+                close_position: None,
+                activation_price: None,
+                callback_rate: None,
+                working_type: None,
+                price_protect: None,
+                new_client_order_id: None,
+                self_trade_prevention_mode: None,
+                price_match: None,
+                good_till_date: None,
+                new_order_resp_type: None,
+            },
+        }
+    }
+}
+
+/// Builds a `CustomOrderRequest`, checking on `build` that the fields sent match
+/// what the order type requires.
+pub struct CustomOrderRequestBuilder {
+    request: CustomOrderRequest,
+}
+
+impl CustomOrderRequestBuilder {
+    pub fn set_position_side(mut self, position_side: PositionSide) -> Self {
+        self.request.position_side = Some(position_side);
+        self
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.request.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_qty(mut self, qty: f64) -> Self {
+        self.request.qty = Some(qty);
+        self
+    }
+
+    pub fn set_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.request.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn set_price(mut self, price: f64) -> Self {
+        self.request.price = Some(price);
+        self
+    }
+
+    pub fn set_stop_price(mut self, stop_price: f64) -> Self {
+        self.request.stop_price = Some(stop_price);
+        self
+    }
+
+    pub fn set_close_position(mut self, close_position: bool) -> Self {
+        self.request.close_position = Some(close_position);
+        self
+    }
+
+    pub fn set_activation_price(mut self, activation_price: f64) -> Self {
+        self.request.activation_price = Some(activation_price);
+        self
+    }
+
+    pub fn set_callback_rate(mut self, callback_rate: f64) -> Self {
+        self.request.callback_rate = Some(callback_rate);
+        self
+    }
+
+    pub fn set_working_type(mut self, working_type: WorkingType) -> Self {
+        self.request.working_type = Some(working_type);
+        self
+    }
+
+    pub fn set_price_protect(mut self, price_protect: bool) -> Self {
+        self.request.price_protect = Some(price_protect);
+        self
+    }
+
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.request.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.request.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn set_price_match(mut self, price_match: PriceMatch) -> Self {
+        self.request.price_match = Some(price_match);
+        self
+    }
+
+    pub fn set_good_till_date(mut self, good_till_date: u64) -> Self {
+        self.request.good_till_date = Some(good_till_date);
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderResponseType) -> Self {
+        self.request.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn build(self) -> Result<CustomOrderRequest> {
+        validate_order(&self.request)?;
+        Ok(self.request)
+    }
+}
+
+/// Change of price and quantity of an open LIMIT order.
+///
+/// The order is identified by `order_id` or `orig_client_order_id`, and exactly one of
+/// `price` and `price_match` must be set.
+pub struct ModifyOrderRequest {
     pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
     pub side: OrderSide,
-    pub position_side: Option<PositionSide>,
-    pub order_type: OrderType,
-    pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
-    pub reduce_only: Option<bool>,
+    pub qty: f64,
     pub price: Option<f64>,
-    pub stop_price: Option<f64>,
-    pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
-    pub working_type: Option<WorkingType>,
-    pub price_protect: Option<f64>,
+    pub price_match: Option<PriceMatch>,
 }
 
 pub struct IncomeRequest {
@@ -155,6 +368,7 @@ pub struct IncomeRequest {
 }
 
 #[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum IncomeType {
     TRANSFER,
     WELCOME_BONUS,
@@ -175,6 +389,9 @@ pub enum IncomeType {
     COIN_SWAP_DEPOSIT,
     COIN_SWAP_WITHDRAW,
     POSITION_LIMIT_INCREASE_FEE,
+    /// Income types this crate does not know about yet.
+    #[serde(other)]
+    UNKNOWN,
 }
 
 impl Display for IncomeType {
@@ -199,6 +416,7 @@ impl Display for IncomeType {
             Self::COIN_SWAP_DEPOSIT => write!(f, "COIN_SWAP_DEPOSIT"),
             Self::COIN_SWAP_WITHDRAW => write!(f, "COIN_SWAP_WITHDRAW"),
             Self::POSITION_LIMIT_INCREASE_FEE => write!(f, "POSITION_LIMIT_INCREASE_FEE"),
+            Self::UNKNOWN => write!(f, "UNKNOWN"),
         }
     }
 }
@@ -208,23 +426,12 @@ impl FuturesAccount {
         &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Buy,
-            position_side: None,
-            order_type: OrderType::Limit,
-            time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: Some(price),
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(buy);
+        let buy = CustomOrderRequest::builder(symbol, OrderSide::Buy, OrderType::Limit)
+            .set_time_in_force(time_in_force)
+            .set_qty(qty.into())
+            .set_price(price)
+            .build()?;
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -234,23 +441,12 @@ impl FuturesAccount {
         &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Sell,
-            position_side: None,
-            order_type: OrderType::Limit,
-            time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: Some(price),
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(sell);
+        let sell = CustomOrderRequest::builder(symbol, OrderSide::Sell, OrderType::Limit)
+            .set_time_in_force(time_in_force)
+            .set_qty(qty.into())
+            .set_price(price)
+            .build()?;
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -262,23 +458,10 @@ impl FuturesAccount {
         S: Into<String>,
         F: Into<f64>,
     {
-        let buy = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Buy,
-            position_side: None,
-            order_type: OrderType::Market,
-            time_in_force: None,
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: None,
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(buy);
+        let buy = CustomOrderRequest::builder(symbol, OrderSide::Buy, OrderType::Market)
+            .set_qty(qty.into())
+            .build()?;
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -290,36 +473,81 @@ impl FuturesAccount {
         S: Into<String>,
         F: Into<f64>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Sell,
-            position_side: None,
-            order_type: OrderType::Market,
-            time_in_force: None,
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: None,
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(sell);
+        let sell = CustomOrderRequest::builder(symbol, OrderSide::Sell, OrderType::Market)
+            .set_qty(qty.into())
+            .build()?;
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
     }
 
-    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<CanceledOrder>
+    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
     where
         S: Into<String>,
     {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("orderId".into(), order_id.to_string());
+        self.query_order(
+            Futures::Order,
+            symbol.into(),
+            "orderId",
+            order_id.to_string(),
+        )
+    }
+
+    pub fn order_status_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.query_order(
+            Futures::Order,
+            symbol.into(),
+            "origClientOrderId",
+            orig_client_order_id,
+        )
+    }
 
+    // Fails with code -2013 if the order is no longer open
+    pub fn get_open_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.query_order(
+            Futures::OpenOrder,
+            symbol.into(),
+            "orderId",
+            order_id.to_string(),
+        )
+    }
+
+    pub fn get_open_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.query_order(
+            Futures::OpenOrder,
+            symbol.into(),
+            "origClientOrderId",
+            orig_client_order_id,
+        )
+    }
+
+    fn query_order(
+        &self, route: Futures, symbol: String, id_name: &str, id: String,
+    ) -> Result<Order> {
+        let parameters = build_order_id(symbol, id_name, id);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(API::Futures(route), Some(request))
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<CanceledOrder>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_order_id(symbol.into(), "orderId", order_id.to_string());
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Futures(Futures::Order), Some(request))
@@ -331,10 +559,7 @@ impl FuturesAccount {
     where
         S: Into<String>,
     {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("origClientOrderId".into(), orig_client_order_id);
-
+        let parameters = build_order_id(symbol.into(), "origClientOrderId", orig_client_order_id);
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Futures(Futures::Order), Some(request))
@@ -346,23 +571,11 @@ impl FuturesAccount {
         S: Into<String>,
         F: Into<f64>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Buy,
-            position_side: None,
-            order_type: OrderType::StopMarket,
-            time_in_force: None,
-            qty: None,
-            reduce_only: None,
-            price: None,
-            stop_price: Some(stop_price.into()),
-            close_position: Some(true),
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(sell);
+        let sell = CustomOrderRequest::builder(symbol, OrderSide::Buy, OrderType::StopMarket)
+            .set_stop_price(stop_price.into())
+            .set_close_position(true)
+            .build()?;
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -374,23 +587,11 @@ impl FuturesAccount {
         S: Into<String>,
         F: Into<f64>,
     {
-        let sell = OrderRequest {
-            symbol: symbol.into(),
-            side: OrderSide::Sell,
-            position_side: None,
-            order_type: OrderType::StopMarket,
-            time_in_force: None,
-            qty: None,
-            reduce_only: None,
-            price: None,
-            stop_price: Some(stop_price.into()),
-            close_position: Some(true),
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
-        let order = self.build_order(sell);
+        let sell = CustomOrderRequest::builder(symbol, OrderSide::Sell, OrderType::StopMarket)
+            .set_stop_price(stop_price.into())
+            .set_close_position(true)
+            .build()?;
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
@@ -398,71 +599,95 @@ impl FuturesAccount {
 
     // Custom order for for professional traders
     pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
-        let order = OrderRequest {
-            symbol: order_request.symbol,
-            side: order_request.side,
-            position_side: order_request.position_side,
-            order_type: order_request.order_type,
-            time_in_force: order_request.time_in_force,
-            qty: order_request.qty,
-            reduce_only: order_request.reduce_only,
-            price: order_request.price,
-            stop_price: order_request.stop_price,
-            close_position: order_request.close_position,
-            activation_price: order_request.activation_price,
-            callback_rate: order_request.callback_rate,
-            working_type: order_request.working_type,
-            price_protect: order_request.price_protect,
-        };
-        let order = self.build_order(order);
+        let order = build_order(order_request);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
     }
 
-    // Custom order for for professional traders
+    // Place up to 5 orders at once; each order succeeds or fails on its own
     pub fn custom_batch_orders(
-        &self, _order_count: u64, order_requests: Vec<CustomOrderRequest>,
-    ) -> Result<Transaction> {
-        let request = String::new();
-        for order_request in order_requests {
-            let order = OrderRequest {
-                symbol: order_request.symbol,
-                side: order_request.side,
-                position_side: order_request.position_side,
-                order_type: order_request.order_type,
-                time_in_force: order_request.time_in_force,
-                qty: order_request.qty,
-                reduce_only: order_request.reduce_only,
-                price: order_request.price,
-                stop_price: order_request.stop_price,
-                close_position: order_request.close_position,
-                activation_price: order_request.activation_price,
-                callback_rate: order_request.callback_rate,
-                working_type: order_request.working_type,
-                price_protect: order_request.price_protect,
-            };
-            let _order = self.build_order(order);
-            // TODO : make a request string for batch orders api
-            // let request = build_signed_request(order, self.recv_window)?;
+        &self, order_requests: Vec<CustomOrderRequest>,
+    ) -> Result<Vec<BatchResult<Transaction>>> {
+        if order_requests.is_empty() || order_requests.len() > MAX_BATCH_ORDERS {
+            return Err(SdkError::Other(format!(
+                "A batch must contain between 1 and {} orders, got {}",
+                MAX_BATCH_ORDERS,
+                order_requests.len()
+            )));
         }
+        let orders: Vec<BTreeMap<String, String>> =
+            order_requests.into_iter().map(build_order).collect();
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), encode_json_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<Transaction>> = self
+            .client
+            .post_signed(API::Futures(Futures::BatchOrders), request)?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    // Change price and quantity of an open LIMIT order
+    pub fn modify_order(&self, modify_request: ModifyOrderRequest) -> Result<Order> {
+        let order = build_modify_order(modify_request)?;
+        let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .put_signed(API::Futures(Futures::Order), request)
     }
 
-    pub fn get_all_orders<S, F, N>(
-        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
-    ) -> Result<Vec<Order>>
+    // Modify up to 5 orders at once; each modification succeeds or fails on its own
+    pub fn modify_batch_orders(
+        &self, modify_requests: Vec<ModifyOrderRequest>,
+    ) -> Result<Vec<BatchResult<Order>>> {
+        if modify_requests.is_empty() || modify_requests.len() > MAX_BATCH_ORDERS {
+            return Err(SdkError::Other(format!(
+                "A batch must contain between 1 and {} orders, got {}",
+                MAX_BATCH_ORDERS,
+                modify_requests.len()
+            )));
+        }
+        let orders = modify_requests
+            .into_iter()
+            .map(build_modify_order)
+            .collect::<Result<Vec<BTreeMap<String, String>>>>()?;
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), encode_json_param(&orders)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<Order>> = self
+            .client
+            .put_signed(API::Futures(Futures::BatchOrders), request)?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    // Modification history of ONE order, identified by order id or client order id
+    pub fn get_order_modify_history<S, O, F, N>(
+        &self, symbol: S, order_id: O, orig_client_order_id: Option<String>, start_time: F,
+        end_time: F, limit: N,
+    ) -> Result<Vec<OrderAmendment>>
     where
         S: Into<String>,
+        O: Into<Option<u64>>,
         F: Into<Option<u64>>,
         N: Into<Option<u16>>,
     {
+        let order_id = order_id.into();
+        if order_id.is_none() && orig_client_order_id.is_none() {
+            return Err(SdkError::Other(
+                "Either orderId or origClientOrderId must be sent".into(),
+            ));
+        }
         let mut parameters = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        if let Some(order_id) = order_id.into() {
+        if let Some(order_id) = order_id {
             parameters.insert("orderId".into(), order_id.to_string());
         }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        }
         if let Some(start_time) = start_time.into() {
             parameters.insert("startTime".into(), start_time.to_string());
         }
@@ -473,6 +698,71 @@ impl FuturesAccount {
             parameters.insert("limit".into(), limit.to_string());
         }
 
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OrderAmendment), Some(request))
+    }
+
+    // Cancel up to 10 orders of ONE symbol by order id
+    pub fn cancel_multiple_orders<S>(
+        &self, symbol: S, order_ids: Vec<u64>,
+    ) -> Result<Vec<BatchResult<CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        self.batch_cancel(symbol.into(), "orderIdList", &order_ids)
+    }
+
+    // Cancel up to 10 orders of ONE symbol by client order id
+    pub fn cancel_multiple_orders_with_client_ids<S>(
+        &self, symbol: S, orig_client_order_ids: Vec<String>,
+    ) -> Result<Vec<BatchResult<CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        self.batch_cancel(
+            symbol.into(),
+            "origClientOrderIdList",
+            &orig_client_order_ids,
+        )
+    }
+
+    fn batch_cancel<T: Serialize>(
+        &self, symbol: String, list_name: &str, ids: &[T],
+    ) -> Result<Vec<BatchResult<CanceledOrder>>> {
+        if ids.is_empty() || ids.len() > MAX_BATCH_CANCELS {
+            return Err(SdkError::Other(format!(
+                "A batch cancel must contain between 1 and {} ids, got {}",
+                MAX_BATCH_CANCELS,
+                ids.len()
+            )));
+        }
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol);
+        parameters.insert(list_name.into(), encode_json_param(ids)?);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let responses: Vec<BatchResponse<CanceledOrder>> = self
+            .client
+            .delete_signed(API::Futures(Futures::BatchOrders), Some(request))?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    pub fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let parameters = build_history(
+            symbol.into(),
+            ("orderId", order_id.into()),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::AllOrders), Some(request))
@@ -486,73 +776,17 @@ impl FuturesAccount {
         F: Into<Option<u64>>,
         N: Into<Option<u16>>,
     {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        if let Some(order_id) = from_id.into() {
-            parameters.insert("fromId".into(), order_id.to_string());
-        }
-        if let Some(start_time) = start_time.into() {
-            parameters.insert("startTime".into(), start_time.to_string());
-        }
-        if let Some(end_time) = end_time.into() {
-            parameters.insert("endTime".into(), end_time.to_string());
-        }
-        if let Some(limit) = limit.into() {
-            parameters.insert("limit".into(), limit.to_string());
-        }
-
+        let parameters = build_history(
+            symbol.into(),
+            ("fromId", from_id.into()),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::UserTrades), Some(request))
     }
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), order.symbol);
-        parameters.insert("side".into(), order.side.to_string());
-        parameters.insert("type".into(), order.order_type.to_string());
-
-        if let Some(position_side) = order.position_side {
-            parameters.insert("positionSide".into(), position_side.to_string());
-        }
-        if let Some(time_in_force) = order.time_in_force {
-            parameters.insert("timeInForce".into(), time_in_force.to_string());
-        }
-        if let Some(qty) = order.qty {
-            parameters.insert("quantity".into(), qty.to_string());
-        }
-        if let Some(reduce_only) = order.reduce_only {
-            parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
-        }
-        if let Some(price) = order.price {
-            parameters.insert("price".into(), price.to_string());
-        }
-        if let Some(stop_price) = order.stop_price {
-            parameters.insert("stopPrice".into(), stop_price.to_string());
-        }
-        if let Some(close_position) = order.close_position {
-            parameters.insert(
-                "closePosition".into(),
-                close_position.to_string().to_uppercase(),
-            );
-        }
-        if let Some(activation_price) = order.activation_price {
-            parameters.insert("activationPrice".into(), activation_price.to_string());
-        }
-        if let Some(callback_rate) = order.callback_rate {
-            parameters.insert("callbackRate".into(), callback_rate.to_string());
-        }
-        if let Some(working_type) = order.working_type {
-            parameters.insert("workingType".into(), working_type.to_string());
-        }
-        if let Some(price_protect) = order.price_protect {
-            parameters.insert(
-                "priceProtect".into(),
-                price_protect.to_string().to_uppercase(),
-            );
-        }
-
-        parameters
-    }
 
     pub fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
@@ -588,10 +822,7 @@ impl FuturesAccount {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("leverage".into(), leverage.to_string());
-
+        let parameters = build_leverage(symbol.into(), leverage);
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::ChangeInitialLeverage), request)
@@ -601,11 +832,7 @@ impl FuturesAccount {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        let margin_type = if isolated { "ISOLATED" } else { "CROSSED" };
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("marginType".into(), margin_type.into());
-
+        let parameters = build_margin_type(symbol.into(), isolated);
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Futures(Futures::MarginType), request)
@@ -618,12 +845,7 @@ impl FuturesAccount {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        let margin = if is_adding_margin { "1" } else { "2" };
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("amount".into(), amount.to_string());
-        parameters.insert("type".into(), margin.into());
-
+        let parameters = build_position_margin(symbol.into(), amount, is_adding_margin);
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Futures(Futures::PositionMargin), request)
@@ -631,16 +853,196 @@ impl FuturesAccount {
     }
 
     pub fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let parameters = build_position_mode(dual_side_position);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::PositionSide), request)
+            .map(|_| ())
+    }
+
+    pub fn get_position_mode(&self) -> Result<PositionMode> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionSide), Some(request))
+    }
+
+    pub fn get_multi_assets_mode(&self) -> Result<MultiAssetsMode> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::MultiAssetsMargin), Some(request))
+    }
+
+    pub fn change_multi_assets_mode(&self, multi_assets_margin: bool) -> Result<()> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        let dual_side = if dual_side_position { "true" } else { "false" };
-        parameters.insert("dualSidePosition".into(), dual_side.into());
+        parameters.insert("multiAssetsMargin".into(), multi_assets_margin.to_string());
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .post_signed::<Empty>(API::Futures(Futures::PositionSide), request)
+            .post_signed::<Empty>(API::Futures(Futures::MultiAssetsMargin), request)
+            .map(|_| ())
+    }
+
+    pub fn get_commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::CommissionRate), Some(request))
+    }
+
+    // ADL quantiles of ONE symbol, or of all symbols with a position if `None`
+    pub fn get_adl_quantile<S>(&self, symbol: S) -> Result<Vec<AdlQuantile>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let quantiles: AdlQuantileResponse = self
+            .client
+            .get_signed(API::Futures(Futures::AdlQuantile), Some(request))?;
+        Ok(quantiles.into())
+    }
+
+    // The user's liquidation and ADL orders
+    pub fn get_force_orders<S, F, N>(
+        &self, symbol: S, auto_close_type: Option<AutoCloseType>, start_time: F, end_time: F,
+        limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(auto_close_type) = auto_close_type {
+            parameters.insert("autoCloseType".into(), auto_close_type.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::ForceOrders), Some(request))
+    }
+
+    // `is_adding_margin` filters on added (true) or reduced (false) margin
+    pub fn get_position_margin_history<S, F, N>(
+        &self, symbol: S, is_adding_margin: Option<bool>, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<PositionMarginChange>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(is_adding_margin) = is_adding_margin {
+            let margin = if is_adding_margin { "1" } else { "2" };
+            parameters.insert("type".into(), margin.into());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionMarginHistory), Some(request))
+    }
+
+    // Margin type, leverage and max notional of ONE symbol, or of all symbols if `None`
+    pub fn get_symbol_config<S>(&self, symbol: S) -> Result<Vec<SymbolConfig>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::SymbolConfig), Some(request))
+    }
+
+    pub fn get_account_config(&self) -> Result<AccountConfig> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::AccountConfig), Some(request))
+    }
+
+    // Trading quantitative rules indicators of ONE symbol, or of the whole account if `None`
+    pub fn get_api_trading_status<S>(&self, symbol: S) -> Result<ApiTradingStatus>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::ApiTradingStatus), Some(request))
+    }
+
+    // Whether trading fees are paid with BNB
+    pub fn get_fee_burn_status(&self) -> Result<FeeBurnStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::FeeBurn), Some(request))
+    }
+
+    pub fn change_fee_burn(&self, fee_burn: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("feeBurn".into(), fee_burn.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::FeeBurn), request)
             .map(|_| ())
     }
 
+    // Cancels all open orders of ONE symbol once `countdown_time` (ms) elapses without
+    // being re-armed. A countdown of 0 disarms it.
+    pub fn countdown_cancel_all<S>(
+        &self, symbol: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::CountdownCancelAll), request)
+    }
+
     pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
     where
         S: Into<String>,
@@ -664,29 +1066,422 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::OpenOrders), Some(request))
     }
 
-    pub fn get_income(
-        &self, income_request: IncomeRequest,
-    ) -> Result<Vec<crate::futures::model::Income>> {
+    // Full income history between `start_time` and `end_time`, fetched page by page
+    pub fn income_history(&self, income_request: IncomeRequest) -> IncomeHistory<'_> {
+        IncomeHistory::new(self, income_request)
+    }
+
+    // Aggregates the full income history per symbol, asset and day
+    pub fn income_report(&self, income_request: IncomeRequest) -> Result<IncomeReport> {
+        let mut report = IncomeReport::new();
+        for income in self.income_history(income_request) {
+            report.add(&income?);
+        }
+        Ok(report)
+    }
+
+    // Asks for a CSV of the income history between two times (at most one year apart)
+    pub fn request_income_download(&self, start_time: u64, end_time: u64) -> Result<DownloadId> {
+        self.request_download(Futures::IncomeAsyn, start_time, end_time)
+    }
+
+    pub fn get_income_download_link(&self, download_id: &str) -> Result<DownloadLink> {
+        self.get_download_link(Futures::IncomeAsynId, download_id)
+    }
+
+    // Requests, waits for and parses the income history CSV between two times
+    pub fn download_income(
+        &self, start_time: u64, end_time: u64, poll_interval: Duration, timeout: Duration,
+    ) -> Result<Vec<Income>> {
+        let csv = self.download(
+            Futures::IncomeAsyn,
+            Futures::IncomeAsynId,
+            start_time,
+            end_time,
+            poll_interval,
+            timeout,
+        )?;
+        parse_income_csv(csv.as_bytes())
+    }
+
+    // Asks for a CSV of the order history between two times (at most one year apart)
+    pub fn request_order_download(&self, start_time: u64, end_time: u64) -> Result<DownloadId> {
+        self.request_download(Futures::OrderAsyn, start_time, end_time)
+    }
+
+    pub fn get_order_download_link(&self, download_id: &str) -> Result<DownloadLink> {
+        self.get_download_link(Futures::OrderAsynId, download_id)
+    }
+
+    // Requests, waits for and parses the order history CSV between two times
+    pub fn download_orders(
+        &self, start_time: u64, end_time: u64, poll_interval: Duration, timeout: Duration,
+    ) -> Result<Vec<Order>> {
+        let csv = self.download(
+            Futures::OrderAsyn,
+            Futures::OrderAsynId,
+            start_time,
+            end_time,
+            poll_interval,
+            timeout,
+        )?;
+        parse_order_csv(csv.as_bytes())
+    }
+
+    // Asks for a CSV of the trade history between two times (at most one year apart)
+    pub fn request_trade_download(&self, start_time: u64, end_time: u64) -> Result<DownloadId> {
+        self.request_download(Futures::TradeAsyn, start_time, end_time)
+    }
+
+    pub fn get_trade_download_link(&self, download_id: &str) -> Result<DownloadLink> {
+        self.get_download_link(Futures::TradeAsynId, download_id)
+    }
+
+    // Requests, waits for and parses the trade history CSV between two times
+    pub fn download_trades(
+        &self, start_time: u64, end_time: u64, poll_interval: Duration, timeout: Duration,
+    ) -> Result<Vec<TradeHistory>> {
+        let csv = self.download(
+            Futures::TradeAsyn,
+            Futures::TradeAsynId,
+            start_time,
+            end_time,
+            poll_interval,
+            timeout,
+        )?;
+        parse_trade_csv(csv.as_bytes())
+    }
+
+    fn request_download(
+        &self, route: Futures, start_time: u64, end_time: u64,
+    ) -> Result<DownloadId> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        if let Some(symbol) = income_request.symbol {
-            parameters.insert("symbol".into(), symbol);
+        parameters.insert("startTime".into(), start_time.to_string());
+        parameters.insert("endTime".into(), end_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(API::Futures(route), Some(request))
+    }
+
+    fn get_download_link(&self, route: Futures, download_id: &str) -> Result<DownloadLink> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("downloadId".into(), download_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(API::Futures(route), Some(request))
+    }
+
+    fn download(
+        &self, request_route: Futures, link_route: Futures, start_time: u64, end_time: u64,
+        poll_interval: Duration, timeout: Duration,
+    ) -> Result<String> {
+        let download_id = self
+            .request_download(request_route, start_time, end_time)?
+            .download_id;
+        let deadline = Instant::now() + timeout;
+        loop {
+            let link = self.get_download_link(link_route, &download_id)?;
+            if link.is_ready() {
+                return self.client.download(&link.url);
+            }
+            if Instant::now() + poll_interval > deadline {
+                return Err(SdkError::Other(format!(
+                    "Download {} not ready after {:?}",
+                    download_id, timeout
+                )));
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+
+    // One page of income history, see `income_history` to walk all of it
+    pub fn get_income(&self, income_request: IncomeRequest) -> Result<Vec<Income>> {
+        let parameters = build_income_request(income_request)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Income), Some(request))
+    }
+}
+
+pub(crate) fn build_order(order: CustomOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), order.order_type.to_string());
+
+    if let Some(position_side) = order.position_side {
+        parameters.insert("positionSide".into(), position_side.to_string());
+    }
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(qty) = order.qty {
+        parameters.insert("quantity".into(), qty.to_string());
+    }
+    if let Some(reduce_only) = order.reduce_only {
+        parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
+    }
+    if let Some(price) = order.price {
+        parameters.insert("price".into(), price.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(close_position) = order.close_position {
+        parameters.insert(
+            "closePosition".into(),
+            close_position.to_string().to_uppercase(),
+        );
+    }
+    if let Some(activation_price) = order.activation_price {
+        parameters.insert("activationPrice".into(), activation_price.to_string());
+    }
+    if let Some(callback_rate) = order.callback_rate {
+        parameters.insert("callbackRate".into(), callback_rate.to_string());
+    }
+    if let Some(working_type) = order.working_type {
+        parameters.insert("workingType".into(), working_type.to_string());
+    }
+    if let Some(price_protect) = order.price_protect {
+        parameters.insert(
+            "priceProtect".into(),
+            price_protect.to_string().to_uppercase(),
+        );
+    }
+    if let Some(new_client_order_id) = order.new_client_order_id {
+        parameters.insert("newClientOrderId".into(), new_client_order_id);
+    }
+    if let Some(mode) = order.self_trade_prevention_mode {
+        parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+    }
+    if let Some(price_match) = order.price_match {
+        parameters.insert("priceMatch".into(), price_match.to_string());
+    }
+    if let Some(good_till_date) = order.good_till_date {
+        parameters.insert("goodTillDate".into(), good_till_date.to_string());
+    }
+    if let Some(new_order_resp_type) = order.new_order_resp_type {
+        parameters.insert("newOrderRespType".into(), new_order_resp_type.to_string());
+    }
+
+    parameters
+}
+
+fn build_modify_order(order: ModifyOrderRequest) -> Result<BTreeMap<String, String>> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("quantity".into(), order.qty.to_string());
+
+    match (order.order_id, order.orig_client_order_id) {
+        (Some(order_id), _) => {
+            parameters.insert("orderId".into(), order_id.to_string());
         }
-        if let Some(income_type) = income_request.income_type {
-            parameters.insert("incomeType".into(), income_type.to_string());
+        (None, Some(orig_client_order_id)) => {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
         }
-        if let Some(start_time) = income_request.start_time {
-            parameters.insert("startTime".into(), start_time.to_string());
+        (None, None) => {
+            return Err(SdkError::Other(
+                "Either orderId or origClientOrderId must be sent".into(),
+            ))
         }
-        if let Some(end_time) = income_request.end_time {
-            parameters.insert("endTime".into(), end_time.to_string());
+    }
+    match (order.price, order.price_match) {
+        (Some(price), None) => {
+            parameters.insert("price".into(), price.to_string());
         }
-        if let Some(limit) = income_request.limit {
-            parameters.insert("limit".into(), limit.to_string());
+        (None, Some(price_match)) => {
+            parameters.insert("priceMatch".into(), price_match.to_string());
         }
+        _ => {
+            return Err(SdkError::Other(
+                "Exactly one of price and priceMatch must be sent".into(),
+            ))
+        }
+    }
 
-        let request = build_signed_request(parameters, self.recv_window)?;
-        println!("{}", request);
-        self.client
-            .get_signed(API::Futures(Futures::Income), Some(request))
+    Ok(parameters)
+}
+
+fn validate_order(order: &CustomOrderRequest) -> Result<()> {
+    let order_type = order.order_type.to_string();
+    let invalid = |reason: &str| -> Result<()> {
+        Err(SdkError::Other(format!(
+            "Invalid {} order: {}",
+            order_type, reason
+        )))
+    };
+    let closes_position = order.close_position == Some(true);
+
+    match order.order_type {
+        OrderType::Limit => {
+            if order.time_in_force.is_none() {
+                return invalid("timeInForce is required");
+            }
+            if order.qty.is_none() {
+                return invalid("quantity is required");
+            }
+            if order.price.is_none() && order.price_match.is_none() {
+                return invalid("price or priceMatch is required");
+            }
+        }
+        OrderType::Market => {
+            if order.qty.is_none() {
+                return invalid("quantity is required");
+            }
+        }
+        OrderType::Stop | OrderType::TakeProfit => {
+            if order.qty.is_none() {
+                return invalid("quantity is required");
+            }
+            if order.price.is_none() && order.price_match.is_none() {
+                return invalid("price or priceMatch is required");
+            }
+            if order.stop_price.is_none() {
+                return invalid("stopPrice is required");
+            }
+        }
+        OrderType::StopMarket | OrderType::TakeProfitMarket => {
+            if order.stop_price.is_none() {
+                return invalid("stopPrice is required");
+            }
+            if order.qty.is_none() && !closes_position {
+                return invalid("quantity is required unless closePosition is true");
+            }
+        }
+        OrderType::TrailingStopMarket => {
+            if order.qty.is_none() {
+                return invalid("quantity is required");
+            }
+            match order.callback_rate {
+                None => return invalid("callbackRate is required"),
+                Some(rate) if !(0.1..=10.0).contains(&rate) => {
+                    return invalid("callbackRate must be between 0.1 and 10")
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if order.price.is_some() && order.price_match.is_some() {
+        return invalid("price and priceMatch cannot be sent together");
+    }
+    if order.callback_rate.is_some() && !matches!(order.order_type, OrderType::TrailingStopMarket) {
+        return invalid("callbackRate is only used by TRAILING_STOP_MARKET");
     }
+    if order.close_position.is_some() {
+        if !matches!(
+            order.order_type,
+            OrderType::StopMarket | OrderType::TakeProfitMarket
+        ) {
+            return invalid("closePosition is only used by STOP_MARKET and TAKE_PROFIT_MARKET");
+        }
+        if closes_position && (order.qty.is_some() || order.reduce_only.is_some()) {
+            return invalid("closePosition cannot be sent with quantity or reduceOnly");
+        }
+    }
+    let good_till_date_tif = matches!(order.time_in_force, Some(TimeInForce::GTD));
+    if good_till_date_tif != order.good_till_date.is_some() {
+        return invalid("goodTillDate is required with, and only with, timeInForce GTD");
+    }
+
+    Ok(())
+}
+
+// JSON encoded, then percent-encoded so that it can be sent (and signed) in the query string
+fn encode_json_param<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    Ok(encode_param(&serde_json::to_string(value)?))
+}
+
+pub(crate) fn build_income_request(
+    income_request: IncomeRequest,
+) -> Result<BTreeMap<String, String>> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    if let Some(symbol) = income_request.symbol {
+        parameters.insert("symbol".into(), symbol);
+    }
+    if let Some(income_type) = income_request.income_type {
+        if income_type == IncomeType::UNKNOWN {
+            return Err(SdkError::Other(
+                "UNKNOWN is not an income type Binance can filter on".into(),
+            ));
+        }
+        parameters.insert("incomeType".into(), income_type.to_string());
+    }
+    if let Some(start_time) = income_request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = income_request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(limit) = income_request.limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+
+    Ok(parameters)
+}
+
+// The order `id` of `symbol`, named `orderId` or `origClientOrderId`
+pub(crate) fn build_order_id(
+    symbol: String, id_name: &str, id: String,
+) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert(id_name.into(), id);
+    parameters
+}
+
+// Filter of the order or trade history of `symbol`, starting from the optional named id
+pub(crate) fn build_history(
+    symbol: String, (id_name, id): (&str, Option<u64>), start_time: Option<u64>,
+    end_time: Option<u64>, limit: Option<u16>,
+) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    if let Some(id) = id {
+        parameters.insert(id_name.into(), id.to_string());
+    }
+    if let Some(start_time) = start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(limit) = limit {
+        parameters.insert("limit".into(), limit.to_string());
+    }
+    parameters
+}
+
+pub(crate) fn build_leverage(symbol: String, leverage: u8) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert("leverage".into(), leverage.to_string());
+    parameters
+}
+
+pub(crate) fn build_margin_type(symbol: String, isolated: bool) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    let margin_type = if isolated { "ISOLATED" } else { "CROSSED" };
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert("marginType".into(), margin_type.into());
+    parameters
+}
+
+pub(crate) fn build_position_margin(
+    symbol: String, amount: f64, is_adding_margin: bool,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    let margin = if is_adding_margin { "1" } else { "2" };
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert("amount".into(), amount.to_string());
+    parameters.insert("type".into(), margin.into());
+    parameters
+}
+
+pub(crate) fn build_position_mode(dual_side_position: bool) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    let dual_side = if dual_side_position { "true" } else { "false" };
+    parameters.insert("dualSidePosition".into(), dual_side.into());
+    parameters
 }
@@ -0,0 +1,105 @@
+//! Dead-man's switch on top of `countdownCancelAll`.
+//!
+//! `CountdownHeartbeat` re-arms the auto-cancel countdown of a set of symbols from a
+//! background thread, as long as the strategy keeps calling `beat`. If the strategy
+//! stalls or the process dies, the countdown runs out and Binance cancels all open
+//! orders of those symbols.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use crate::errors::{Result, SdkError};
+use crate::futures::account::FuturesAccount;
+
+pub struct CountdownHeartbeat {
+    account: FuturesAccount,
+    symbols: Vec<String>,
+    started: Instant,
+    // Milliseconds since `started` of the last `beat`
+    last_beat: Arc<AtomicU64>,
+    stop: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl CountdownHeartbeat {
+    /// Arms a `countdown` for every symbol and re-arms it every `interval` from a background
+    /// thread, while the last `beat` is more recent than `countdown`.
+    ///
+    /// `interval` should be well below `countdown` so that a slow request does not let the
+    /// countdown expire. Failures to arm a symbol are passed to `on_error`.
+    pub fn start<F>(
+        account: FuturesAccount, symbols: Vec<String>, countdown: Duration, interval: Duration,
+        mut on_error: F,
+    ) -> Self
+    where
+        F: FnMut(&str, &SdkError) + Send + 'static,
+    {
+        let started = Instant::now();
+        let last_beat = Arc::new(AtomicU64::new(0));
+        let (stop, stopped) = mpsc::channel::<()>();
+
+        let thread_account = account.clone();
+        let thread_symbols = symbols.clone();
+        let thread_last_beat = last_beat.clone();
+        let countdown_ms = countdown.as_millis() as u64;
+        let handle = thread::spawn(move || loop {
+            let now = started.elapsed().as_millis() as u64;
+            let healthy =
+                now.saturating_sub(thread_last_beat.load(Ordering::SeqCst)) < countdown_ms;
+            if healthy {
+                for symbol in &thread_symbols {
+                    if let Err(e) =
+                        thread_account.countdown_cancel_all(symbol.as_str(), countdown_ms)
+                    {
+                        on_error(symbol, &e);
+                    }
+                }
+            }
+
+            match stopped.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => continue,
+                _ => break,
+            }
+        });
+
+        CountdownHeartbeat {
+            account,
+            symbols,
+            started,
+            last_beat,
+            stop: Some(stop),
+            handle: Some(handle),
+        }
+    }
+
+    /// Marks the strategy as healthy.
+    pub fn beat(&self) {
+        self.last_beat
+            .store(self.started.elapsed().as_millis() as u64, Ordering::SeqCst);
+    }
+
+    /// Stops re-arming. The countdowns already armed keep running.
+    pub fn stop(&mut self) {
+        self.stop.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+
+    /// Stops re-arming and cancels the countdown of every symbol.
+    pub fn stop_and_disarm(mut self) -> Result<()> {
+        self.stop();
+        for symbol in &self.symbols {
+            self.account.countdown_cancel_all(symbol.as_str(), 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for CountdownHeartbeat {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
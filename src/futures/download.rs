@@ -0,0 +1,291 @@
+//! Parsing of the files produced by the asynchronous download endpoints
+//! (`/fapi/v1/income/asyn`, `/fapi/v1/order/asyn` and `/fapi/v1/trade/asyn`).
+//!
+//! Columns are matched by name, ignoring case, spaces and punctuation, so both the
+//! API field names (`tranId`) and their spelled-out headers (`Transaction Id`) are
+//! accepted. Times may be given in milliseconds or as `yyyy-mm-dd hh:mm:ss` (UTC).
+//! Every column the models need must be in the header, or parsing fails with
+//! `SdkError::Csv`; empty cells of these columns read as empty text, zero or false.
+//! Only columns that exports may leave out are optional, e.g. `info` and `tradeId`
+//! of incomes, the trailing stop columns of orders, and `positionSide` of trades, which
+//! one-way mode exports omit and which is then `BOTH`.
+
+use std::collections::HashMap;
+use std::io::Read;
+use csv::StringRecord;
+use crate::errors::{Result, SdkError};
+use crate::futures::account::IncomeType;
+use crate::futures::model::{Income, Order, TradeHistory};
+
+pub fn parse_income_csv<R: Read>(reader: R) -> Result<Vec<Income>> {
+    let table = Table::read(reader)?;
+    table
+        .rows()
+        .map(|row| {
+            Ok(Income {
+                symbol: row.text(&["symbol"])?,
+                income_type: parse_income_type(&row.required(&["incomeType", "type"])?),
+                income: row.float(&["income", "amount"])?,
+                asset: row.text(&["asset", "coin"])?,
+                info: row.optional(&["info"]).unwrap_or_default(),
+                time: row.time(&["time", "date", "dateUtc", "transactionTime"])?,
+                tran_id: row.integer(&["tranId", "transactionId"])?,
+                trade_id: row.optional(&["tradeId"]).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+pub fn parse_order_csv<R: Read>(reader: R) -> Result<Vec<Order>> {
+    let table = Table::read(reader)?;
+    table
+        .rows()
+        .map(|row| {
+            let order_type = row.required(&["type", "orderType"])?;
+            Ok(Order {
+                client_order_id: row.text(&["clientOrderId"])?,
+                cum_qty: row.float(&["cumQty", "executedQty", "executed"])?,
+                cum_quote: row.float(&["cumQuote", "total"])?,
+                executed_qty: row.float(&["executedQty", "executed"])?,
+                order_id: row.required_integer(&["orderId", "orderNo"])?,
+                avg_price: row.float(&["avgPrice", "averagePrice", "average"])?,
+                orig_qty: row.float(&["origQty", "quantity", "amount"])?,
+                price: row.float(&["price"])?,
+                side: row.required(&["side", "direction"])?,
+                reduce_only: row.boolean(&["reduceOnly"])?,
+                position_side: row.required(&["positionSide"])?,
+                status: row.required(&["status"])?,
+                stop_price: row.float(&["stopPrice", "triggerPrice"])?,
+                close_position: row.boolean(&["closePosition"])?,
+                symbol: row.required(&["symbol", "pair"])?,
+                time_in_force: row.text(&["timeInForce"])?,
+                orig_type: row
+                    .optional(&["origType"])
+                    .unwrap_or_else(|| order_type.clone()),
+                order_type,
+                activation_price: row.optional_float(&["activatePrice", "activationPrice"])?,
+                price_rate: row.optional_float(&["priceRate", "callbackRate"])?,
+                update_time: row.time(&["updateTime", "time", "date", "dateUtc"])?,
+                working_type: row.text(&["workingType"])?,
+                price_protect: row.boolean(&["priceProtect"])?,
+                price_match: row.optional(&["priceMatch"]),
+                self_trade_prevention_mode: row.optional(&["selfTradePreventionMode"]),
+                good_till_date: row
+                    .optional(&["goodTillDate"])
+                    .map(|date| parse_integer(&date))
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_trade_csv<R: Read>(reader: R) -> Result<Vec<TradeHistory>> {
+    let table = Table::read(reader)?;
+    table
+        .rows()
+        .map(|row| {
+            let side = row.required(&["side"])?;
+            Ok(TradeHistory {
+                buyer: match row.optional(&["buyer"]) {
+                    Some(buyer) => parse_bool(&buyer)?,
+                    None => side.eq_ignore_ascii_case("BUY"),
+                },
+                commission: row.float(&["commission", "fee"])?,
+                commission_asset: row.text(&["commissionAsset", "feeAsset", "feeCoin"])?,
+                id: row.integer(&["id", "tradeId"])?,
+                maker: row.boolean(&["maker"])?,
+                order_id: row.integer(&["orderId"])?,
+                price: row.float(&["price"])?,
+                qty: row.float(&["qty", "quantity"])?,
+                quote_qty: row.float(&["quoteQty", "amount"])?,
+                realized_pnl: row.float(&["realizedPnl", "realizedProfit"])?,
+                side,
+                position_side: if row.has(&["positionSide"]) {
+                    row.required(&["positionSide"])?
+                } else {
+                    "BOTH".into()
+                },
+                symbol: row.required(&["symbol"])?,
+                time: row.time(&["time", "date", "dateUtc"])?,
+            })
+        })
+        .collect()
+}
+
+struct Table {
+    columns: HashMap<String, usize>,
+    records: Vec<StringRecord>,
+}
+
+impl Table {
+    fn read<R: Read>(reader: R) -> Result<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+        let columns = reader
+            .headers()?
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (normalize(name), index))
+            .collect();
+        let records = reader.records().collect::<std::result::Result<_, _>>()?;
+        Ok(Table { columns, records })
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        self.records
+            .iter()
+            .enumerate()
+            .map(move |(index, record)| Row {
+                table: self,
+                record,
+                line: index + 2,
+            })
+    }
+}
+
+struct Row<'a> {
+    table: &'a Table,
+    record: &'a StringRecord,
+    // Line in the file, the header being line 1
+    line: usize,
+}
+
+impl Row<'_> {
+    fn has(&self, names: &[&str]) -> bool {
+        names
+            .iter()
+            .any(|name| self.table.columns.contains_key(&normalize(name)))
+    }
+
+    // Value of an optional column, `None` if the column is missing or the cell empty
+    fn optional(&self, names: &[&str]) -> Option<String> {
+        names
+            .iter()
+            .filter_map(|name| self.table.columns.get(&normalize(name)))
+            .filter_map(|&index| self.record.get(index))
+            .find(|value| !value.is_empty())
+            .map(String::from)
+    }
+
+    // Value of a column that must be in the header, `None` if the cell is empty
+    fn cell(&self, names: &[&str]) -> Result<Option<String>> {
+        if !self.has(names) {
+            return Err(csv_error(format!("Missing column {}", names[0])));
+        }
+        Ok(self.optional(names))
+    }
+
+    fn required(&self, names: &[&str]) -> Result<String> {
+        self.cell(names)?
+            .ok_or_else(|| csv_error(format!("Empty column {} at line {}", names[0], self.line)))
+    }
+
+    fn text(&self, names: &[&str]) -> Result<String> {
+        Ok(self.cell(names)?.unwrap_or_default())
+    }
+
+    fn float(&self, names: &[&str]) -> Result<f64> {
+        self.cell(names)?
+            .map_or(Ok(0.0), |value| parse_float(&value))
+    }
+
+    fn optional_float(&self, names: &[&str]) -> Result<f64> {
+        self.optional(names)
+            .map_or(Ok(0.0), |value| parse_float(&value))
+    }
+
+    fn integer(&self, names: &[&str]) -> Result<u64> {
+        self.cell(names)?
+            .map_or(Ok(0), |value| parse_integer(&value))
+    }
+
+    fn required_integer(&self, names: &[&str]) -> Result<u64> {
+        parse_integer(&self.required(names)?)
+    }
+
+    fn boolean(&self, names: &[&str]) -> Result<bool> {
+        self.cell(names)?
+            .map_or(Ok(false), |value| parse_bool(&value))
+    }
+
+    fn time(&self, names: &[&str]) -> Result<u64> {
+        parse_time(&self.required(names)?)
+    }
+}
+
+fn csv_error(message: String) -> SdkError {
+    SdkError::Csv(csv::Error::from(std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        message,
+    )))
+}
+
+// "Transaction Id", "transaction_id" and "tranId" style headers compare equal
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn parse_float(value: &str) -> Result<f64> {
+    // Amounts may carry their asset, e.g. "-0.375 USDT"
+    let number = value.split_whitespace().next().unwrap_or_default();
+    Ok(number.replace(',', "").parse()?)
+}
+
+fn parse_integer(value: &str) -> Result<u64> {
+    value
+        .parse()
+        .map_err(|_| SdkError::Other(format!("Invalid integer {}", value)))
+}
+
+fn parse_bool(value: &str) -> Result<bool> {
+    value
+        .to_ascii_lowercase()
+        .parse()
+        .map_err(|_| SdkError::Other(format!("Invalid boolean {}", value)))
+}
+
+fn parse_income_type(value: &str) -> IncomeType {
+    serde_json::from_value(serde_json::Value::String(value.to_ascii_uppercase()))
+        .unwrap_or(IncomeType::UNKNOWN)
+}
+
+// Milliseconds since the epoch, or "yyyy-mm-dd hh:mm:ss" in UTC
+fn parse_time(value: &str) -> Result<u64> {
+    if let Ok(millis) = value.parse::<u64>() {
+        return Ok(millis);
+    }
+
+    let invalid = || SdkError::Other(format!("Invalid time {}", value));
+    let numbers = value
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<u64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<u64>>>()?;
+    if numbers.len() < 3 {
+        return Err(invalid());
+    }
+    let field = |index: usize| numbers.get(index).copied().unwrap_or(0);
+    let (year, month, day) = (field(0), field(1), field(2));
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || year < 1970 {
+        return Err(invalid());
+    }
+
+    let seconds =
+        days_from_civil(year, month, day) * 86_400 + field(3) * 3600 + field(4) * 60 + field(5);
+    Ok(seconds * 1000)
+}
+
+// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
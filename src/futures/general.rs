@@ -13,9 +13,7 @@ pub struct FuturesGeneral {
 impl FuturesGeneral {
     // Test connectivity
     pub fn ping(&self) -> Result<String> {
-        self.client
-            .get::<Empty>(API::Futures(Futures::Ping), None)?;
-        Ok("pong".into())
+        ping(&self.client, API::Futures(Futures::Ping))
     }
 
     // Check server time
@@ -34,17 +32,24 @@ impl FuturesGeneral {
     where
         S: Into<String>,
     {
-        let upper_symbol = symbol.into().to_uppercase();
-        match self.exchange_info() {
-            Ok(info) => {
-                for item in info.symbols {
-                    if item.symbol == upper_symbol {
-                        return Ok(item);
-                    }
-                }
-                Err(SdkError::Other("Symbol not found".into()))
-            }
-            Err(e) => Err(e),
-        }
+        find_symbol(self.exchange_info()?.symbols, symbol.into(), |item| {
+            &item.symbol
+        })
     }
 }
+
+// Shared with the COIN-M and options clients, which only differ by route and model
+
+pub(crate) fn ping(client: &Client, route: API) -> Result<String> {
+    client.get::<Empty>(route, None)?;
+    Ok("pong".into())
+}
+
+// The symbol of the exchange information named `symbol`, in any case
+pub(crate) fn find_symbol<T>(symbols: Vec<T>, symbol: String, name: fn(&T) -> &str) -> Result<T> {
+    let upper_symbol = symbol.to_uppercase();
+    symbols
+        .into_iter()
+        .find(|item| name(item) == upper_symbol)
+        .ok_or_else(|| SdkError::Other("Symbol not found".into()))
+}
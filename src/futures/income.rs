@@ -0,0 +1,220 @@
+//! Income history pagination and P&L attribution.
+//!
+//! `GET /fapi/v1/income` returns at most 1000 rows per call, and Binance only keeps
+//! about three months of history. `IncomeHistory` walks a time range in 7-day windows
+//! and pages through each window, and `IncomeReport` aggregates the rows per symbol,
+//! asset and day.
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::errors::{Result, SdkError};
+use crate::futures::account::{FuturesAccount, IncomeRequest, IncomeType};
+use crate::futures::model::Income;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+const WINDOW_MS: u64 = 7 * DAY_MS;
+const HISTORY_MS: u64 = 90 * DAY_MS;
+const PAGE_LIMIT: u32 = 1000;
+
+/// Iterator over the full income history of a `FuturesAccount`.
+///
+/// Starts at `start_time` (or 90 days before `end_time` if unset) and stops at
+/// `end_time` (or now). The `limit` of the request is ignored, pages are always
+/// 1000 rows. Iteration stops after the first error, including when more than 1000 rows
+/// share one millisecond, as rows are never skipped.
+pub struct IncomeHistory<'a> {
+    account: &'a FuturesAccount,
+    symbol: Option<String>,
+    income_type: Option<IncomeType>,
+    cursor: u64,
+    end_time: u64,
+    // Rows already returned at `cursor`, which a page starting at `cursor` returns again
+    seen_at_cursor: HashSet<(u64, IncomeType, String)>,
+    buffer: VecDeque<Income>,
+    done: bool,
+}
+
+impl<'a> IncomeHistory<'a> {
+    pub(crate) fn new(account: &'a FuturesAccount, income_request: IncomeRequest) -> Self {
+        let end_time = income_request.end_time.unwrap_or_else(now_ms);
+        let cursor = income_request
+            .start_time
+            .unwrap_or_else(|| end_time.saturating_sub(HISTORY_MS));
+        IncomeHistory {
+            account,
+            symbol: income_request.symbol,
+            income_type: income_request.income_type,
+            cursor,
+            end_time,
+            seen_at_cursor: HashSet::new(),
+            buffer: VecDeque::new(),
+            done: cursor > end_time,
+        }
+    }
+
+    fn fetch_page(&mut self) -> Result<()> {
+        let window_end = (self.cursor + WINDOW_MS - 1).min(self.end_time);
+        let page = self.account.get_income(IncomeRequest {
+            symbol: self.symbol.clone(),
+            income_type: self.income_type,
+            start_time: Some(self.cursor),
+            end_time: Some(window_end),
+            limit: Some(PAGE_LIMIT),
+        })?;
+        let full_page = page.len() >= PAGE_LIMIT as usize;
+
+        let fresh: Vec<Income> = page
+            .into_iter()
+            .filter(|income| {
+                income.time != self.cursor || !self.seen_at_cursor.contains(&income_key(income))
+            })
+            .collect();
+
+        match fresh.last() {
+            // More rows may remain in this window, continue from the last timestamp
+            Some(last) if full_page => {
+                let last_time = last.time;
+                if last_time != self.cursor {
+                    self.seen_at_cursor.clear();
+                    self.cursor = last_time;
+                }
+                self.seen_at_cursor.extend(
+                    fresh
+                        .iter()
+                        .filter(|income| income.time == last_time)
+                        .map(income_key),
+                );
+            }
+            // A full page without anything new: the rest of this millisecond cannot be paged
+            None if full_page => {
+                return Err(SdkError::Other(format!(
+                    "More than {} income rows at {}, the history cannot be paged past them",
+                    PAGE_LIMIT, self.cursor
+                )));
+            }
+            _ => {
+                self.seen_at_cursor.clear();
+                self.cursor = window_end + 1;
+            }
+        }
+        if self.cursor > self.end_time {
+            self.done = true;
+        }
+
+        self.buffer.extend(fresh);
+        Ok(())
+    }
+}
+
+impl Iterator for IncomeHistory<'_> {
+    type Item = Result<Income>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.buffer.is_empty() && !self.done {
+            if let Err(e) = self.fetch_page() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
+
+/// Aggregation key of an `IncomeReport`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IncomeKey {
+    /// Empty for account-wide income such as transfers.
+    pub symbol: String,
+    pub asset: String,
+    /// Days since the Unix epoch, in UTC.
+    pub day: u64,
+}
+
+impl IncomeKey {
+    /// Start of the day in milliseconds.
+    pub fn day_start(&self) -> u64 {
+        self.day * DAY_MS
+    }
+}
+
+/// Income summed per `IncomeType` for every symbol, asset and day.
+#[derive(Debug, Clone, Default)]
+pub struct IncomeReport {
+    entries: BTreeMap<IncomeKey, BTreeMap<IncomeType, f64>>,
+}
+
+impl IncomeReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, income: &Income) {
+        let key = IncomeKey {
+            symbol: income.symbol.clone(),
+            asset: income.asset.clone(),
+            day: income.time / DAY_MS,
+        };
+        *self
+            .entries
+            .entry(key)
+            .or_default()
+            .entry(income.income_type)
+            .or_insert(0.0) += income.income;
+    }
+
+    pub fn entries(&self) -> &BTreeMap<IncomeKey, BTreeMap<IncomeType, f64>> {
+        &self.entries
+    }
+
+    /// Sum of one income type for a symbol and asset over all days.
+    pub fn total(&self, symbol: &str, asset: &str, income_type: IncomeType) -> f64 {
+        self.entries
+            .iter()
+            .filter(|(key, _)| key.symbol == symbol && key.asset == asset)
+            .filter_map(|(_, amounts)| amounts.get(&income_type))
+            .sum()
+    }
+
+    /// Sum of one income type per asset over all symbols and days.
+    pub fn totals_by_asset(&self, income_type: IncomeType) -> BTreeMap<String, f64> {
+        let mut totals = BTreeMap::new();
+        for (key, amounts) in &self.entries {
+            if let Some(amount) = amounts.get(&income_type) {
+                *totals.entry(key.asset.clone()).or_insert(0.0) += amount;
+            }
+        }
+        totals
+    }
+
+    /// Net income of all types per (symbol, asset) over all days.
+    pub fn net_by_symbol(&self) -> BTreeMap<(String, String), f64> {
+        let mut totals = BTreeMap::new();
+        for (key, amounts) in &self.entries {
+            *totals
+                .entry((key.symbol.clone(), key.asset.clone()))
+                .or_insert(0.0) += amounts.values().sum::<f64>();
+        }
+        totals
+    }
+}
+
+impl FromIterator<Income> for IncomeReport {
+    fn from_iter<I: IntoIterator<Item = Income>>(incomes: I) -> Self {
+        let mut report = IncomeReport::new();
+        for income in incomes {
+            report.add(&income);
+        }
+        report
+    }
+}
+
+fn income_key(income: &Income) -> (u64, IncomeType, String) {
+    (income.tran_id, income.income_type, income.symbol.clone())
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
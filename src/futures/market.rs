@@ -12,7 +12,7 @@
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
-- [ ] `Notional and Leverage Brackets (MARKET_DATA)`
+- [x] `Notional and Leverage Brackets (MARKET_DATA)`
 - [ ] `Open Interest Statistics (MARKET_DATA)`
 - [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
 - [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
@@ -23,11 +23,13 @@
 use crate::util::{build_request, build_signed_request};
 use crate::futures::model::{
     AggTrades, BookTickers, KlineSummaries, KlineSummary, LiquidationOrders, MarkPrices,
-    OpenInterest, OpenInterestHist, OrderBook, PriceStats, SymbolPrice, Tickers, Trades,
+    OpenInterest, OpenInterestHist, OrderBook, PriceStats, SymbolBrackets, SymbolBracketsResponse,
+    SymbolPrice, Tickers, Trades,
 };
 use crate::client::Client;
 use crate::errors::Result;
 use std::collections::BTreeMap;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use crate::api::API;
 use crate::api::Futures;
@@ -49,12 +51,12 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-
-        self.client.get(API::Futures(Futures::Depth), Some(request))
+        get_depth(
+            &self.client,
+            API::Futures(Futures::Depth),
+            symbol.into(),
+            None,
+        )
     }
 
     // Order book at a custom depth. Currently supported values
@@ -63,22 +65,19 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("limit".into(), depth.to_string());
-        let request = build_request(parameters);
-        self.client.get(API::Futures(Futures::Depth), Some(request))
+        get_depth(
+            &self.client,
+            API::Futures(Futures::Depth),
+            symbol.into(),
+            Some(depth),
+        )
     }
 
     pub fn get_trades<S>(&self, symbol: S) -> Result<Trades>
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-        self.client
-            .get(API::Futures(Futures::Trades), Some(request))
+        get_for_symbol(&self.client, API::Futures(Futures::Trades), symbol.into())
     }
 
     // TODO This may be incomplete, as it hasn't been tested
@@ -90,22 +89,14 @@ impl FuturesMarket {
         S2: Into<Option<u64>>,
         S3: Into<Option<u16>>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        parameters.insert("symbol".into(), symbol.into());
-
-        // Add three optional parameters
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(fi) = from_id.into() {
-            parameters.insert("fromId".into(), format!("{}", fi));
-        }
-
-        let request = build_signed_request(parameters, self.recv_window)?;
-
-        self.client
-            .get_signed(API::Futures(Futures::HistoricalTrades), Some(request))
+        get_historical_trades(
+            &self.client,
+            API::Futures(Futures::HistoricalTrades),
+            self.recv_window,
+            symbol.into(),
+            from_id.into(),
+            limit.into(),
+        )
     }
 
     pub fn get_agg_trades<S1, S2, S3, S4, S5>(
@@ -118,28 +109,15 @@ impl FuturesMarket {
         S4: Into<Option<u64>>,
         S5: Into<Option<u16>>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        parameters.insert("symbol".into(), symbol.into());
-
-        // Add three optional parameters
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(st) = start_time.into() {
-            parameters.insert("startTime".into(), format!("{}", st));
-        }
-        if let Some(et) = end_time.into() {
-            parameters.insert("endTime".into(), format!("{}", et));
-        }
-        if let Some(fi) = from_id.into() {
-            parameters.insert("fromId".into(), format!("{}", fi));
-        }
-
-        let request = build_request(parameters);
-
-        self.client
-            .get(API::Futures(Futures::AggTrades), Some(request))
+        get_agg_trades(
+            &self.client,
+            API::Futures(Futures::AggTrades),
+            symbol.into(),
+            from_id.into(),
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        )
     }
 
     // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
@@ -155,34 +133,16 @@ impl FuturesMarket {
         S5: Into<Option<u64>>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("interval".into(), interval.into());
-
-        // Add three optional parameters
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(st) = start_time.into() {
-            parameters.insert("startTime".into(), format!("{}", st));
-        }
-        if let Some(et) = end_time.into() {
-            parameters.insert("endTime".into(), format!("{}", et));
-        }
-
-        let request = build_request(parameters);
-
-        let data: Vec<Vec<Value>> = self
-            .client
-            .get(API::Futures(Futures::Klines), Some(request))?;
-
-        let klines = KlineSummaries::AllKlineSummaries(
-            data.iter()
-                .map(|row| row.try_into())
-                .collect::<Result<Vec<KlineSummary>>>()?,
-        );
-
-        Ok(klines)
+        get_kline_summaries(
+            &self.client,
+            API::Futures(Futures::Klines),
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
     }
 
     // 24hr ticker price change statistics
@@ -190,13 +150,11 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-
-        self.client
-            .get(API::Futures(Futures::Ticker24hr), Some(request))
+        get_for_symbol(
+            &self.client,
+            API::Futures(Futures::Ticker24hr),
+            symbol.into(),
+        )
     }
 
     // 24hr ticker price change statistics for all symbols
@@ -209,13 +167,11 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-
-        self.client
-            .get(API::Futures(Futures::TickerPrice), Some(request))
+        get_for_symbol(
+            &self.client,
+            API::Futures(Futures::TickerPrice),
+            symbol.into(),
+        )
     }
 
     // Latest price for all symbols.
@@ -234,11 +190,11 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-        self.client
-            .get(API::Futures(Futures::BookTicker), Some(request))
+        get_for_symbol(
+            &self.client,
+            API::Futures(Futures::BookTicker),
+            symbol.into(),
+        )
     }
 
     pub fn get_mark_prices(&self) -> Result<MarkPrices> {
@@ -253,11 +209,11 @@ impl FuturesMarket {
     where
         S: Into<String>,
     {
-        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
-        let request = build_request(parameters);
-        self.client
-            .get(API::Futures(Futures::OpenInterest), Some(request))
+        get_for_symbol(
+            &self.client,
+            API::Futures(Futures::OpenInterest),
+            symbol.into(),
+        )
     }
 
     pub fn open_interest_statistics<S1, S2, S3, S4, S5>(
@@ -273,19 +229,132 @@ impl FuturesMarket {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("period".into(), period.into());
-
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(st) = start_time.into() {
-            parameters.insert("startTime".into(), format!("{}", st));
-        }
-        if let Some(et) = end_time.into() {
-            parameters.insert("endTime".into(), format!("{}", et));
-        }
+        insert_range(
+            &mut parameters,
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
 
         let request = build_request(parameters);
         self.client
             .get(API::Futures(Futures::OpenInterestHist), Some(request))
     }
+
+    // Notional and leverage brackets for ONE symbol, or for all symbols if `None`
+    pub fn get_leverage_brackets<S>(&self, symbol: S) -> Result<Vec<SymbolBrackets>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<SymbolBracketsResponse>(
+                API::Futures(Futures::LeverageBracket),
+                Some(request),
+            )
+            .map(Into::into)
+    }
+}
+
+// Market data requests shared with the COIN-M and options markets, which only differ by
+// route and model
+
+pub(crate) fn get_for_symbol<T: DeserializeOwned>(
+    client: &Client, route: API, symbol: String,
+) -> Result<T> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    let request = build_request(parameters);
+    client.get(route, Some(request))
+}
+
+pub(crate) fn get_depth<T: DeserializeOwned>(
+    client: &Client, route: API, symbol: String, depth: Option<u64>,
+) -> Result<T> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    if let Some(depth) = depth {
+        parameters.insert("limit".into(), depth.to_string());
+    }
+    let request = build_request(parameters);
+    client.get(route, Some(request))
+}
+
+pub(crate) fn get_historical_trades<T: DeserializeOwned>(
+    client: &Client, route: API, recv_window: u64, symbol: String, from_id: Option<u64>,
+    limit: Option<u16>,
+) -> Result<T> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    if let Some(lt) = limit {
+        parameters.insert("limit".into(), format!("{}", lt));
+    }
+    if let Some(fi) = from_id {
+        parameters.insert("fromId".into(), format!("{}", fi));
+    }
+
+    let request = build_signed_request(parameters, recv_window)?;
+    client.get_signed(route, Some(request))
+}
+
+pub(crate) fn get_agg_trades<T: DeserializeOwned>(
+    client: &Client, route: API, symbol: String, from_id: Option<u64>, start_time: Option<u64>,
+    end_time: Option<u64>, limit: Option<u16>,
+) -> Result<T> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    insert_range(&mut parameters, start_time, end_time, limit);
+    if let Some(fi) = from_id {
+        parameters.insert("fromId".into(), format!("{}", fi));
+    }
+
+    let request = build_request(parameters);
+    client.get(route, Some(request))
+}
+
+// Klines of the symbol or pair and interval in `parameters`
+pub(crate) fn get_klines<T: DeserializeOwned>(
+    client: &Client, route: API, mut parameters: BTreeMap<String, String>, limit: Option<u16>,
+    start_time: Option<u64>, end_time: Option<u64>,
+) -> Result<T> {
+    insert_range(&mut parameters, start_time, end_time, limit);
+    let request = build_request(parameters);
+    client.get(route, Some(request))
+}
+
+// Klines returned as arrays, as on USDⓈ-M and COIN-M
+pub(crate) fn get_kline_summaries(
+    client: &Client, route: API, parameters: BTreeMap<String, String>, limit: Option<u16>,
+    start_time: Option<u64>, end_time: Option<u64>,
+) -> Result<KlineSummaries> {
+    let data: Vec<Vec<Value>> = get_klines(client, route, parameters, limit, start_time, end_time)?;
+
+    let klines = KlineSummaries::AllKlineSummaries(
+        data.iter()
+            .map(|row| row.try_into())
+            .collect::<Result<Vec<KlineSummary>>>()?,
+    );
+
+    Ok(klines)
+}
+
+// Adds the optional time range and limit of a history request
+pub(crate) fn insert_range(
+    parameters: &mut BTreeMap<String, String>, start_time: Option<u64>, end_time: Option<u64>,
+    limit: Option<u16>,
+) {
+    if let Some(lt) = limit {
+        parameters.insert("limit".into(), format!("{}", lt));
+    }
+    if let Some(st) = start_time {
+        parameters.insert("startTime".into(), format!("{}", st));
+    }
+    if let Some(et) = end_time {
+        parameters.insert("endTime".into(), format!("{}", et));
+    }
 }
@@ -1,5 +1,8 @@
 pub mod account;
+pub mod countdown;
+pub mod download;
 pub mod general;
+pub mod income;
 pub mod market;
 pub mod model;
 pub mod userstream;
@@ -1,5 +1,8 @@
+use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, string_or_u64};
+use crate::errors::BinanceContentError;
+use crate::futures::account::IncomeType;
 
 pub use crate::model::{
     Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
@@ -206,6 +209,80 @@ pub struct OpenInterestHist {
     pub timestamp: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub bracket: u16,
+    pub initial_leverage: u16,
+    #[serde(with = "string_or_float")]
+    pub notional_cap: f64,
+    #[serde(with = "string_or_float")]
+    pub notional_floor: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub cum: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolBrackets {
+    pub symbol: String,
+    /// Only returned when the user has a custom bracket ratio.
+    #[serde(default, with = "string_or_float_opt")]
+    pub notional_coef: Option<f64>,
+    pub brackets: Vec<LeverageBracket>,
+}
+
+impl SymbolBrackets {
+    /// Bracket a position of the given notional falls into, i.e. the one with
+    /// `notionalFloor <= notional < notionalCap`.
+    ///
+    /// The sign of the notional is ignored, so short positions can be passed as is.
+    /// Returns `None` when the notional reaches the cap of the last bracket.
+    pub fn bracket_for_notional(&self, notional: f64) -> Option<&LeverageBracket> {
+        let notional = notional.abs();
+        self.brackets
+            .iter()
+            .find(|b| notional >= b.notional_floor && notional < b.notional_cap)
+    }
+
+    /// Maintenance margin rate applied to a position of the given notional.
+    pub fn maintenance_margin_rate(&self, notional: f64) -> Option<f64> {
+        self.bracket_for_notional(notional)
+            .map(|b| b.maint_margin_ratio)
+    }
+
+    /// Maintenance margin required for a position of the given notional,
+    /// i.e. `notional * maintMarginRatio - cum`.
+    pub fn maintenance_amount(&self, notional: f64) -> Option<f64> {
+        self.bracket_for_notional(notional)
+            .map(|b| notional.abs() * b.maint_margin_ratio - b.cum)
+    }
+
+    /// Highest leverage that can be used to open a position of the given notional.
+    pub fn max_leverage(&self, notional: f64) -> Option<u16> {
+        self.bracket_for_notional(notional)
+            .map(|b| b.initial_leverage)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum SymbolBracketsResponse {
+    Many(Vec<SymbolBrackets>),
+    One(SymbolBrackets),
+}
+
+impl From<SymbolBracketsResponse> for Vec<SymbolBrackets> {
+    fn from(response: SymbolBracketsResponse) -> Self {
+        match response {
+            SymbolBracketsResponse::Many(brackets) => brackets,
+            SymbolBracketsResponse::One(brackets) => vec![brackets],
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -241,7 +318,44 @@ pub struct Order {
     pub price_rate: f64,
     pub update_time: u64,
     pub working_type: String,
+    /// Not returned for liquidation orders.
+    #[serde(default)]
     pub price_protect: bool,
+    #[serde(default)]
+    pub price_match: Option<String>,
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(default)]
+    pub good_till_date: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub time: u64,
+    pub amendment: Amendment,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendedValue,
+    pub orig_qty: AmendedValue,
+    /// Number of times the order has been modified so far.
+    pub count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AmendedValue {
+    #[serde(with = "string_or_float")]
+    pub before: f64,
+    #[serde(with = "string_or_float")]
+    pub after: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -282,6 +396,25 @@ pub struct Transaction {
     price_protect: bool,
 }
 
+/// Outcome of one order of a batch: each order is accepted or rejected on its own.
+pub type BatchResult<T> = std::result::Result<T, BinanceContentError>;
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum BatchResponse<T> {
+    Ok(T),
+    Err(BinanceContentError),
+}
+
+impl<T> From<BatchResponse<T>> for BatchResult<T> {
+    fn from(response: BatchResponse<T>) -> Self {
+        match response {
+            BatchResponse::Ok(value) => Ok(value),
+            BatchResponse::Err(error) => Err(error),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CanceledOrder {
@@ -483,6 +616,169 @@ pub struct ChangeLeverageResponse {
     pub symbol: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    pub dual_side_position: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsMode {
+    pub multi_assets_margin: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeBurnStatus {
+    pub fee_burn: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: f64,
+}
+
+/// ADL queue position from 0 (lowest priority) to 4 (highest priority).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantiles,
+}
+
+/// `both` is set in one-way mode, `long`, `short` and `hedge` in hedge mode.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct AdlQuantiles {
+    #[serde(default)]
+    pub long: Option<u8>,
+    #[serde(default)]
+    pub short: Option<u8>,
+    #[serde(default)]
+    pub hedge: Option<u8>,
+    #[serde(default)]
+    pub both: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum AdlQuantileResponse {
+    Many(Vec<AdlQuantile>),
+    One(AdlQuantile),
+}
+
+impl From<AdlQuantileResponse> for Vec<AdlQuantile> {
+    fn from(response: AdlQuantileResponse) -> Self {
+        match response {
+            AdlQuantileResponse::Many(quantiles) => quantiles,
+            AdlQuantileResponse::One(quantile) => vec![quantile],
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    /// 1 when margin was added, 2 when it was reduced.
+    #[serde(rename = "type")]
+    pub change_type: u8,
+    pub delta_type: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolConfig {
+    pub symbol: String,
+    pub margin_type: String,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub leverage: u8,
+    #[serde(with = "string_or_float")]
+    pub max_notional_value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountConfig {
+    pub fee_tier: u64,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub dual_side_position: bool,
+    pub multi_assets_margin: bool,
+    pub trade_group_id: i64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    /// Indicators per symbol, and under `ACCOUNT` for the account-wide ones.
+    pub indicators: HashMap<String, Vec<TradingIndicator>>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingIndicator {
+    pub is_locked: bool,
+    pub planned_recover_time: u64,
+    pub indicator: String,
+    pub value: f64,
+    pub trigger_value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    /// Countdown in milliseconds, 0 when the countdown was cancelled.
+    #[serde(with = "string_or_u64")]
+    pub countdown_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadId {
+    /// Average time taken to generate the file over the last 30 days, in milliseconds.
+    pub avg_cost_timestamp_of_last30d: u64,
+    pub download_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadLink {
+    pub download_id: String,
+    /// `processing` or `completed`.
+    pub status: String,
+    /// Empty while processing.
+    pub url: String,
+    #[serde(default)]
+    pub notified: bool,
+    /// -1 while processing.
+    pub expiration_timestamp: i64,
+    #[serde(default)]
+    pub is_expired: Option<bool>,
+}
+
+impl DownloadLink {
+    pub fn is_ready(&self) -> bool {
+        self.status == "completed" && !self.url.is_empty()
+    }
+}
+
 fn default_stop_price() -> f64 {
     0.0
 }
@@ -616,7 +912,7 @@ pub struct OrderTradeEvent {
 #[serde(rename_all = "camelCase")]
 pub struct Income {
     pub symbol: String,
-    pub income_type: String,
+    pub income_type: IncomeType,
     #[serde(with = "string_or_float")]
     pub income: f64,
     pub asset: String,
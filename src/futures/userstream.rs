@@ -13,16 +13,36 @@ pub struct FuturesUserStream {
 impl FuturesUserStream {
     // User Stream
     pub fn start(&self) -> Result<UserDataStream> {
-        self.client.post(API::Futures(Futures::UserDataStream))
+        start(&self.client, API::Futures(Futures::UserDataStream))
     }
 
     pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
-        self.client
-            .put(API::Futures(Futures::UserDataStream), listen_key)
+        keep_alive(
+            &self.client,
+            API::Futures(Futures::UserDataStream),
+            listen_key,
+        )
     }
 
     pub fn close(&self, listen_key: &str) -> Result<Success> {
-        self.client
-            .delete(API::Futures(Futures::UserDataStream), listen_key)
+        close(
+            &self.client,
+            API::Futures(Futures::UserDataStream),
+            listen_key,
+        )
     }
 }
+
+// Listen key calls, shared with the COIN-M and options streams on their own route
+
+pub(crate) fn start(client: &Client, route: API) -> Result<UserDataStream> {
+    client.post(route)
+}
+
+pub(crate) fn keep_alive(client: &Client, route: API, listen_key: &str) -> Result<Success> {
+    client.put(route, listen_key)
+}
+
+pub(crate) fn close(client: &Client, route: API, listen_key: &str) -> Result<Success> {
+    client.delete(route, listen_key)
+}
@@ -1,49 +1,38 @@
 use crate::errors::Result;
-use crate::config::Config;
+use crate::config::{combined_stream_url, raw_stream_url, Config};
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BookTickerEvent, ContinuousKlineEvent, DayTickerEvent,
     DepthOrderBookEvent, IndexKlineEvent, IndexPriceEvent, KlineEvent, LiquidationEvent,
     MarkPriceEvent, MiniTickerEvent, OrderBook, TradeEvent, UserDataStreamExpiredEvent,
 };
 use crate::futures::model;
-use url::Url;
+use crate::websockets::{ConnectionState, Lifecycle, ReconnectPolicy, StreamConnection};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::net::TcpStream;
-use tungstenite::{connect, Message};
+use std::time::Duration;
+use tungstenite::Message;
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
 
-#[allow(clippy::all)]
-enum FuturesWebsocketAPI {
-    Default,
-    MultiStream,
-    Custom(String),
-}
-
 pub enum FuturesMarket {
     USDM,
     COINM,
     Vanilla,
+    /// User data stream of a Portfolio Margin account, opened with its listen key.
+    PortfolioMargin,
 }
 
-impl FuturesWebsocketAPI {
-    fn params(self, market: &FuturesMarket, subscription: &str) -> String {
-        let baseurl = match market {
-            FuturesMarket::USDM => "wss://fstream.binance.com",
-            FuturesMarket::COINM => "wss://dstream.binance.com",
-            FuturesMarket::Vanilla => "wss://vstream.binance.com",
-        };
-
+impl FuturesMarket {
+    // Websocket endpoint of the market in `config`
+    fn ws_endpoint<'c>(&self, config: &'c Config) -> &'c str {
         match self {
-            FuturesWebsocketAPI::Default => {
-                format!("{}/ws/{}", baseurl, subscription)
-            }
-            FuturesWebsocketAPI::MultiStream => {
-                format!("{}/stream?streams={}", baseurl, subscription)
-            }
-            FuturesWebsocketAPI::Custom(url) => url,
+            FuturesMarket::USDM => &config.futures_ws_endpoint,
+            FuturesMarket::COINM => &config.delivery_ws_endpoint,
+            FuturesMarket::Vanilla => &config.options_ws_endpoint,
+            FuturesMarket::PortfolioMargin => &config.portfolio_margin_ws_endpoint,
         }
     }
 }
@@ -51,6 +40,14 @@ impl FuturesWebsocketAPI {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum FuturesWebsocketEvent {
+    /// The supervised event loop started on a live connection.
+    Connected,
+    /// The connection dropped or reached its maximum age, with the reason.
+    Disconnected(String),
+    /// Connected again, after `attempts` tries.
+    Reconnected {
+        attempts: u32,
+    },
     AccountUpdate(AccountUpdateEvent),
     OrderTrade(model::OrderTradeEvent),
     AggrTrades(AggrTradesEvent),
@@ -75,6 +72,7 @@ pub enum FuturesWebsocketEvent {
 pub struct FuturesWebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(FuturesWebsocketEvent) -> Result<()> + 'a>,
+    connection: ConnectionState,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -109,39 +107,75 @@ impl<'a> FuturesWebSockets<'a> {
         FuturesWebSockets {
             socket: None,
             handler: Box::new(handler),
+            connection: ConnectionState::default(),
         }
     }
 
-    pub fn connect(&mut self, market: &FuturesMarket, subscription: &'a str) -> Result<()> {
-        self.connect_wss(&FuturesWebsocketAPI::Default.params(market, subscription))
+    pub fn connect(&mut self, market: &FuturesMarket, subscription: &str) -> Result<()> {
+        self.connect_with_config(market, subscription, &Config::default())
     }
 
+    // Connects to the stream on the `config` endpoint of the market
     pub fn connect_with_config(
-        &mut self, market: &FuturesMarket, subscription: &'a str, config: &'a Config,
+        &mut self, market: &FuturesMarket, subscription: &str, config: &Config,
     ) -> Result<()> {
-        self.connect_wss(
-            &FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, subscription),
+        self.connect_streams(
+            &raw_stream_url(market.ws_endpoint(config), subscription)?,
+            vec![subscription.to_string()],
         )
     }
 
-    pub fn connect_multiple_streams(
-        &mut self, market: &FuturesMarket, endpoints: &[String],
+    // Connects to several streams, given as names or as `streams::Stream`
+    pub fn connect_multiple_streams<S: Display>(
+        &mut self, market: &FuturesMarket, endpoints: &[S],
     ) -> Result<()> {
-        self.connect_wss(&FuturesWebsocketAPI::MultiStream.params(market, &endpoints.join("/")))
+        self.connect_multiple_streams_with_config(market, endpoints, &Config::default())
     }
 
-    fn connect_wss(&mut self, wss: &str) -> Result<()> {
-        let url = Url::parse(wss)?;
-        match connect(url) {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            Err(e) => Err(crate::errors::SdkError::Other(format!(
-                "Error during handshake: {}",
-                e
-            ))),
-        }
+    // Connects to several streams on the combined stream endpoint of the market in `config`
+    pub fn connect_multiple_streams_with_config<S: Display>(
+        &mut self, market: &FuturesMarket, endpoints: &[S], config: &Config,
+    ) -> Result<()> {
+        let endpoints: Vec<String> = endpoints.iter().map(|e| e.to_string()).collect();
+        self.connect_streams(
+            &combined_stream_url(market.ws_endpoint(config), &endpoints)?,
+            endpoints,
+        )
+    }
+
+    /// Wait up to `timeout` for Binance to answer subscription and property requests, 10 seconds
+    /// by default.
+    pub fn set_control_response_timeout(mut self, timeout: Duration) -> Self {
+        self.connection.control_response_timeout = Some(timeout);
+        self
+    }
+
+    // Streams of the current connection, including the ones subscribed after connecting
+    pub fn streams(&self) -> &[String] {
+        &self.connection.streams
+    }
+
+    /// Subscribe to more streams on the open connection.
+    ///
+    /// Events received while waiting for Binance to confirm go to the handler. Control
+    /// messages are throttled to the limit of 5 per second.
+    pub fn subscribe<S: Display>(&mut self, streams: &[S]) -> Result<()> {
+        self.subscribe_streams(streams.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// Unsubscribe from streams of the open connection.
+    pub fn unsubscribe<S: Display>(&mut self, streams: &[S]) -> Result<()> {
+        self.unsubscribe_streams(streams.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// Streams of the open connection, as reported by Binance.
+    pub fn list_subscriptions(&mut self) -> Result<Vec<String>> {
+        self.request_subscriptions()
+    }
+
+    /// Wrap events as `{"stream": ..., "data": ...}`, as on multi-stream connections.
+    pub fn set_combined(&mut self, combined: bool) -> Result<()> {
+        self.set_combined_property(combined)
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
@@ -225,4 +259,38 @@ impl<'a> FuturesWebSockets<'a> {
             "Running loop closed".to_string(),
         ))
     }
+
+    /// Like `event_loop`, but reconnects to the same streams instead of returning an error
+    /// when the connection drops, and ahead of Binance closing it after 24 hours.
+    ///
+    /// Sends `Connected` to the handler on start, then `Disconnected` and `Reconnected`
+    /// around each reconnect. Returns an error once `policy.max_attempts` attempts in a row
+    /// failed, or if the handler fails.
+    pub fn event_loop_with_reconnect(
+        &mut self, running: &AtomicBool, policy: &ReconnectPolicy,
+    ) -> Result<()> {
+        self.supervise(running, policy)
+    }
+}
+
+impl StreamConnection for FuturesWebSockets<'_> {
+    fn socket(&mut self) -> &mut Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)> {
+        &mut self.socket
+    }
+
+    fn state(&mut self) -> &mut ConnectionState {
+        &mut self.connection
+    }
+
+    fn handle_text(&mut self, msg: &str) -> Result<()> {
+        self.handle_msg(msg)
+    }
+
+    fn handle_lifecycle(&mut self, event: Lifecycle) -> Result<()> {
+        (self.handler)(match event {
+            Lifecycle::Connected => FuturesWebsocketEvent::Connected,
+            Lifecycle::Disconnected(reason) => FuturesWebsocketEvent::Disconnected(reason),
+            Lifecycle::Reconnected { attempts } => FuturesWebsocketEvent::Reconnected { attempts },
+        })
+    }
 }
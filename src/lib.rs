@@ -24,10 +24,21 @@ pub mod model;
 pub mod account;
 pub mod api;
 pub mod config;
+pub mod convert;
 pub mod general;
+pub mod margin;
 pub mod market;
+pub mod order_book;
 pub mod savings;
+pub mod streams;
+pub mod sub_account;
 pub mod userstream;
 pub mod websockets;
 
 pub mod futures;
+
+pub mod delivery;
+
+pub mod options;
+
+pub mod portfolio_margin;
@@ -0,0 +1,515 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use crate::util::build_signed_request;
+use crate::model::{
+    BorrowRepayRecord, InterestRecord, IsolatedMarginAccount, MarginAccountInformation,
+    MarginOrder, MaxBorrowable, MaxTransferable, OcoOrder, OrderCanceled, PaginatedResponse,
+    Success, Transaction, TransactionId, UserDataStream,
+};
+use crate::account::{build_order, OrderRequest, OrderSide, OrderType, TimeInForce};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::api::API;
+use crate::api::Sapi;
+
+/// Cross and isolated margin trading.
+///
+/// Orders are built like the spot orders of `Account`. Every call targets the cross
+/// margin account unless an isolated symbol is given.
+#[derive(Clone)]
+pub struct Margin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Borrowing and repaying attached to a margin order.
+pub enum SideEffectType {
+    NoSideEffect,
+    /// Borrow what the order needs.
+    MarginBuy,
+    /// Repay the debt with the proceeds of the order.
+    AutoRepay,
+    /// Borrow what the order needs and repay the debt once it is filled.
+    AutoBorrowRepay,
+}
+
+impl Display for SideEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSideEffect => write!(f, "NO_SIDE_EFFECT"),
+            Self::MarginBuy => write!(f, "MARGIN_BUY"),
+            Self::AutoRepay => write!(f, "AUTO_REPAY"),
+            Self::AutoBorrowRepay => write!(f, "AUTO_BORROW_REPAY"),
+        }
+    }
+}
+
+pub enum BorrowRepayType {
+    Borrow,
+    Repay,
+}
+
+impl Display for BorrowRepayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Borrow => write!(f, "BORROW"),
+            Self::Repay => write!(f, "REPAY"),
+        }
+    }
+}
+
+pub struct MarginOrderRequest {
+    pub symbol: String,
+    pub qty: f64,
+    /// Ignored when 0, as for MARKET orders.
+    pub price: f64,
+    pub stop_price: Option<f64>,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub new_client_order_id: Option<String>,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+/// One-cancels-the-other order: a LIMIT_MAKER leg at `price` and a stop leg at `stop_price`,
+/// which is a STOP_LOSS_LIMIT at `stop_limit_price` if set, or a STOP_LOSS otherwise.
+pub struct MarginOcoRequest {
+    pub symbol: String,
+    pub order_side: OrderSide,
+    pub qty: f64,
+    pub price: f64,
+    pub stop_price: f64,
+    pub stop_limit_price: Option<f64>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
+    pub is_isolated: bool,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+pub struct BorrowRepayHistoryRequest {
+    pub borrow_repay_type: BorrowRepayType,
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub tx_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1.
+    pub current: Option<u32>,
+    /// Rows per page, at most 100.
+    pub size: Option<u32>,
+}
+
+impl BorrowRepayHistoryRequest {
+    pub fn new(borrow_repay_type: BorrowRepayType) -> Self {
+        BorrowRepayHistoryRequest {
+            borrow_repay_type,
+            asset: None,
+            isolated_symbol: None,
+            tx_id: None,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InterestHistoryRequest {
+    pub asset: Option<String>,
+    pub isolated_symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1.
+    pub current: Option<u32>,
+    /// Rows per page, at most 100.
+    pub size: Option<u32>,
+}
+
+impl Margin {
+    // Borrow on the cross margin account, or on ONE isolated pair
+    pub fn borrow<S>(
+        &self, asset: S, amount: f64, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Borrow,
+            asset.into(),
+            amount,
+            isolated_symbol,
+        )
+    }
+
+    // Repay on the cross margin account, or on ONE isolated pair
+    pub fn repay<S>(
+        &self, asset: S, amount: f64, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Repay,
+            asset.into(),
+            amount,
+            isolated_symbol,
+        )
+    }
+
+    pub fn borrow_repay_history(
+        &self, history_request: BorrowRepayHistoryRequest,
+    ) -> Result<PaginatedResponse<BorrowRepayRecord>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), history_request.borrow_repay_type.to_string());
+        if let Some(asset) = history_request.asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(isolated_symbol) = history_request.isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        if let Some(tx_id) = history_request.tx_id {
+            parameters.insert("txId".into(), tx_id.to_string());
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(current) = history_request.current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = history_request.size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginBorrowRepay), Some(request))
+    }
+
+    // Cross margin limit buy
+    pub fn limit_buy<S, F>(
+        &self, symbol: S, qty: F, price: f64, side_effect_type: Option<SideEffectType>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(MarginOrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            is_isolated: false,
+            side_effect_type,
+        })
+    }
+
+    // Cross margin limit sell
+    pub fn limit_sell<S, F>(
+        &self, symbol: S, qty: F, price: f64, side_effect_type: Option<SideEffectType>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(MarginOrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            is_isolated: false,
+            side_effect_type,
+        })
+    }
+
+    // Cross margin market buy
+    pub fn market_buy<S, F>(
+        &self, symbol: S, qty: F, side_effect_type: Option<SideEffectType>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(MarginOrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: 0.0,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            is_isolated: false,
+            side_effect_type,
+        })
+    }
+
+    // Cross margin market sell
+    pub fn market_sell<S, F>(
+        &self, symbol: S, qty: F, side_effect_type: Option<SideEffectType>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.custom_order(MarginOrderRequest {
+            symbol: symbol.into(),
+            qty: qty.into(),
+            price: 0.0,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+            is_isolated: false,
+            side_effect_type,
+        })
+    }
+
+    pub fn custom_order(&self, order_request: MarginOrderRequest) -> Result<Transaction> {
+        let mut parameters = build_order(OrderRequest {
+            symbol: order_request.symbol,
+            qty: order_request.qty,
+            price: order_request.price,
+            stop_price: order_request.stop_price,
+            order_side: order_request.order_side,
+            order_type: order_request.order_type,
+            time_in_force: order_request.time_in_force,
+            new_client_order_id: order_request.new_client_order_id,
+        });
+        insert_margin_parameters(
+            &mut parameters,
+            order_request.is_isolated,
+            order_request.side_effect_type,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOrder), request)
+    }
+
+    pub fn oco_order(&self, oco_request: MarginOcoRequest) -> Result<OcoOrder> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), oco_request.symbol);
+        parameters.insert("side".into(), oco_request.order_side.to_string());
+        parameters.insert("quantity".into(), oco_request.qty.to_string());
+        parameters.insert("price".into(), oco_request.price.to_string());
+        parameters.insert("stopPrice".into(), oco_request.stop_price.to_string());
+        if let Some(stop_limit_price) = oco_request.stop_limit_price {
+            parameters.insert("stopLimitPrice".into(), stop_limit_price.to_string());
+            let time_in_force = oco_request
+                .stop_limit_time_in_force
+                .unwrap_or(TimeInForce::GTC);
+            parameters.insert("stopLimitTimeInForce".into(), time_in_force.to_string());
+        }
+        if let Some(list_client_order_id) = oco_request.list_client_order_id {
+            parameters.insert("listClientOrderId".into(), list_client_order_id);
+        }
+        insert_margin_parameters(
+            &mut parameters,
+            oco_request.is_isolated,
+            oco_request.side_effect_type,
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::MarginOrderOco), request)
+    }
+
+    pub fn cancel_oco_order<S>(
+        &self, symbol: S, order_list_id: i64, is_isolated: bool,
+    ) -> Result<OcoOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+        insert_margin_parameters(&mut parameters, is_isolated, None);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrderList), Some(request))
+    }
+
+    pub fn order_status<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<MarginOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        insert_margin_parameters(&mut parameters, is_isolated, None);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOrder), Some(request))
+    }
+
+    pub fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        insert_margin_parameters(&mut parameters, is_isolated, None);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::MarginOrder), Some(request))
+    }
+
+    // Open orders of ONE symbol, or of all cross margin symbols if `None`
+    pub fn get_open_orders<S>(&self, symbol: S, is_isolated: bool) -> Result<Vec<MarginOrder>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        insert_margin_parameters(&mut parameters, is_isolated, None);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginOpenOrders), Some(request))
+    }
+
+    // Largest amount of `asset` that can currently be borrowed
+    pub fn max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxBorrowable), Some(request))
+    }
+
+    // Largest amount of `asset` that can currently be transferred out
+    pub fn max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginMaxTransferable), Some(request))
+    }
+
+    pub fn interest_history(
+        &self, history_request: InterestHistoryRequest,
+    ) -> Result<PaginatedResponse<InterestRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(asset) = history_request.asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(isolated_symbol) = history_request.isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(current) = history_request.current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = history_request.size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginInterestHistory), Some(request))
+    }
+
+    // Balances and margin level of the cross margin account
+    pub fn cross_account(&self) -> Result<MarginAccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginAccount), Some(request))
+    }
+
+    // Isolated margin pairs, at most 5 symbols, or all pairs if empty
+    pub fn isolated_account(&self, symbols: &[&str]) -> Result<IsolatedMarginAccount> {
+        let mut parameters = BTreeMap::new();
+        if !symbols.is_empty() {
+            parameters.insert("symbols".into(), symbols.join(","));
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::MarginIsolatedAccount), Some(request))
+    }
+
+    // User Stream of the cross margin account
+    pub fn start_user_stream(&self) -> Result<UserDataStream> {
+        self.client.post(API::Savings(Sapi::MarginUserDataStream))
+    }
+
+    pub fn keep_alive_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Savings(Sapi::MarginUserDataStream), listen_key)
+    }
+
+    pub fn close_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Savings(Sapi::MarginUserDataStream), listen_key)
+    }
+
+    fn borrow_repay(
+        &self, borrow_repay_type: BorrowRepayType, asset: String, amount: f64,
+        isolated_symbol: Option<String>,
+    ) -> Result<TransactionId> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset);
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), borrow_repay_type.to_string());
+        match isolated_symbol {
+            Some(symbol) => {
+                parameters.insert("isIsolated".into(), "TRUE".into());
+                parameters.insert("symbol".into(), symbol);
+            }
+            None => {
+                parameters.insert("isIsolated".into(), "FALSE".into());
+            }
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<TransactionId>(API::Savings(Sapi::MarginBorrowRepay), request)
+    }
+}
+
+fn insert_margin_parameters(
+    parameters: &mut BTreeMap<String, String>, is_isolated: bool,
+    side_effect_type: Option<SideEffectType>,
+) {
+    if is_isolated {
+        parameters.insert("isIsolated".into(), "TRUE".into());
+    }
+    if let Some(side_effect_type) = side_effect_type {
+        parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
 
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, Value};
@@ -183,13 +184,87 @@ pub struct OrderCanceled {
     pub order_id: Option<u64>,
     pub client_order_id: Option<String>,
 }
+/// Source and destination wallets of a universal transfer.
+///
+/// MAIN is the spot wallet, UMFUTURE and CMFUTURE the USDⓈ-M and COIN-M futures
+/// wallets, MARGIN the cross margin wallet and ISOLATEDMARGIN an isolated margin pair.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniversalTransferType {
+    MAIN_C2C,
+    MAIN_UMFUTURE,
+    MAIN_CMFUTURE,
+    MAIN_MARGIN,
+    MAIN_FUNDING,
+    MAIN_OPTION,
+    MAIN_PORTFOLIO_MARGIN,
+    FUNDING_MAIN,
+    FUNDING_UMFUTURE,
+    FUNDING_CMFUTURE,
+    FUNDING_MARGIN,
+    FUNDING_OPTION,
+    FUNDING_C2C,
+    UMFUTURE_MAIN,
+    UMFUTURE_MARGIN,
+    UMFUTURE_FUNDING,
+    UMFUTURE_OPTION,
+    CMFUTURE_MAIN,
+    CMFUTURE_MARGIN,
+    CMFUTURE_FUNDING,
+    MARGIN_MAIN,
+    MARGIN_UMFUTURE,
+    MARGIN_CMFUTURE,
+    MARGIN_ISOLATEDMARGIN,
+    MARGIN_OPTION,
+    MARGIN_FUNDING,
+    ISOLATEDMARGIN_MARGIN,
+    ISOLATEDMARGIN_ISOLATEDMARGIN,
+    OPTION_MAIN,
+    OPTION_UMFUTURE,
+    OPTION_MARGIN,
+    OPTION_FUNDING,
+    C2C_MAIN,
+    C2C_FUNDING,
+    PORTFOLIO_MARGIN_MAIN,
+}
+
+impl UniversalTransferType {
+    /// Whether the transfer leaves an isolated margin pair, given as `fromSymbol`.
+    pub fn requires_from_symbol(&self) -> bool {
+        matches!(
+            self,
+            Self::ISOLATEDMARGIN_MARGIN | Self::ISOLATEDMARGIN_ISOLATEDMARGIN
+        )
+    }
+
+    /// Whether the transfer goes to an isolated margin pair, given as `toSymbol`.
+    pub fn requires_to_symbol(&self) -> bool {
+        matches!(
+            self,
+            Self::MARGIN_ISOLATEDMARGIN | Self::ISOLATEDMARGIN_ISOLATEDMARGIN
+        )
+    }
+}
+
+impl fmt::Display for UniversalTransferType {
+    // Variants are named after their API value
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub enum SpotFuturesTransferType {
-    SpotToUsdtFutures = 1,
-    UsdtFuturesToSpot = 2,
-    SpotToCoinFutures = 3,
-    CoinFuturesToSpot = 4,
+pub struct UniversalTransfer {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    /// CONFIRMED, FAILED or PENDING.
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -222,6 +297,14 @@ pub struct Transaction {
     pub type_name: String,
     pub side: String,
     pub fills: Option<Vec<FillInfo>>,
+    /// Margin orders only.
+    #[serde(default)]
+    pub is_isolated: Option<bool>,
+    /// Amount borrowed by a MARGIN_BUY or AUTO_BORROW_REPAY margin order.
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<f64>,
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
 }
 
 fn default_stop_price() -> f64 {
@@ -1309,6 +1392,78 @@ pub struct Network {
     pub withdraw_integer_multiple: Option<String>,
 }
 
+impl Network {
+    /// Checks a withdrawal against the rules of this network before it is submitted.
+    ///
+    /// The address must match `address_regex`, a memo is only accepted when the network
+    /// has a `memo_regex` and must match it, and the amount must be at least `withdraw_min`
+    /// and a multiple of `withdraw_integer_multiple`. Patterns the `regex` crate cannot
+    /// compile are not checked here.
+    pub fn validate_withdrawal(
+        &self, address: &str, address_tag: Option<&str>, amount: f64,
+    ) -> Result<()> {
+        if !self.withdraw_enable {
+            return Err(SdkError::Other(format!(
+                "Withdrawals of {} on {} are disabled",
+                self.coin, self.network
+            )));
+        }
+        if !regex_matches(&self.address_regex, address) {
+            return Err(SdkError::Other(format!(
+                "Address {} is not a valid {} address",
+                address, self.network
+            )));
+        }
+        if let Some(address_tag) = address_tag.filter(|tag| !tag.is_empty()) {
+            if self.memo_regex.is_empty() {
+                return Err(SdkError::Other(format!(
+                    "{} addresses do not take a memo",
+                    self.network
+                )));
+            }
+            if !regex_matches(&self.memo_regex, address_tag) {
+                return Err(SdkError::Other(format!(
+                    "Memo {} is not a valid {} memo",
+                    address_tag, self.network
+                )));
+            }
+        }
+        if amount < self.withdraw_min {
+            return Err(SdkError::Other(format!(
+                "Amount {} is below the minimum withdrawal of {} {}",
+                amount, self.withdraw_min, self.coin
+            )));
+        }
+        let multiple = self
+            .withdraw_integer_multiple
+            .as_deref()
+            .filter(|multiple| !multiple.is_empty())
+            .map(str::parse::<f64>)
+            .transpose()?
+            .filter(|multiple| *multiple > 0.0);
+        if let Some(multiple) = multiple {
+            let steps = amount / multiple;
+            // Tolerate the rounding error of the division
+            if (steps - steps.round()).abs() > 1e-6 {
+                return Err(SdkError::Other(format!(
+                    "Amount {} is not a multiple of {}",
+                    amount, multiple
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Empty patterns, and patterns the regex crate cannot compile (lookaround,
+// backreferences), accept any value: Binance still checks them on submit
+fn regex_matches(pattern: &str, value: &str) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+    regex::Regex::new(pattern).map_or(true, |regex| regex.is_match(value))
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
@@ -1332,12 +1487,151 @@ pub struct DepositAddress {
     pub url: String,
 }
 
+/// Entry of the deposit address list of a coin.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressInfo {
+    pub coin: String,
+    pub address: String,
+    pub tag: String,
+    /// 1 for the default address of the coin.
+    pub is_default: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    /// 0: pending, 6: credited but cannot withdraw, 7: wrong deposit, 8: waiting user
+    /// confirm, 1: success, 2: rejected.
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    #[serde(default)]
+    pub complete_time: Option<u64>,
+    /// 0: external transfer, 1: internal transfer.
+    pub transfer_type: u8,
+    /// Confirmations, as "current/required".
+    pub confirm_times: String,
+    #[serde(default)]
+    pub unlock_confirm: u32,
+    /// 0: spot wallet, 1: funding wallet.
+    pub wallet_type: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    /// 0: email sent, 2: awaiting approval, 3: rejected, 4: processing, 6: completed.
+    pub status: u8,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: Option<String>,
+    #[serde(default)]
+    pub tx_id: String,
+    /// UTC time, as "yyyy-mm-dd hh:mm:ss".
+    pub apply_time: String,
+    pub network: String,
+    /// 0: external transfer, 1: internal transfer.
+    pub transfer_type: u8,
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+    /// Reason of a failed withdrawal.
+    #[serde(default)]
+    pub info: String,
+    #[serde(default)]
+    pub confirm_no: u32,
+    /// 0: spot wallet, 1: funding wallet.
+    pub wallet_type: u8,
+    #[serde(default)]
+    pub tx_key: String,
+    /// UTC time, as "yyyy-mm-dd hh:mm:ss".
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PaginatedResponse<T> {
-    #[serde(rename = "rows")]
+    // Omitted by some endpoints when there are no rows
+    #[serde(rename = "rows", default = "Vec::new")]
     pub data: Vec<T>,
     pub total: usize,
 }
+
+impl<T> PaginatedResponse<T> {
+    /// Iterates over the rows of all pages of an endpoint paginated with `current`
+    /// (starting at 1) and `size`.
+    ///
+    /// `fetch` is called with the page number and the page size until a page comes
+    /// back short or `total` rows have been returned.
+    pub fn pages<F>(size: u32, fetch: F) -> Pages<T, F>
+    where
+        F: FnMut(u32, u32) -> Result<PaginatedResponse<T>>,
+    {
+        Pages {
+            fetch,
+            size,
+            current: 1,
+            returned: 0,
+            buffer: VecDeque::new(),
+            done: size == 0,
+        }
+    }
+}
+
+/// Iterator returned by `PaginatedResponse::pages`. Iteration stops after the first error.
+pub struct Pages<T, F> {
+    fetch: F,
+    size: u32,
+    current: u32,
+    returned: usize,
+    buffer: VecDeque<T>,
+    done: bool,
+}
+
+impl<T, F> Iterator for Pages<T, F>
+where
+    F: FnMut(u32, u32) -> Result<PaginatedResponse<T>>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && !self.done {
+            match (self.fetch)(self.current, self.size) {
+                Ok(page) => {
+                    self.current += 1;
+                    self.returned += page.data.len();
+                    self.done = page.data.len() < self.size as usize || self.returned >= page.total;
+                    self.buffer.extend(page.data);
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        self.buffer.pop_front().map(Ok)
+    }
+}
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FlexibleProductInfo {
@@ -1373,7 +1667,7 @@ pub struct FlexibleProductInfo {
 
     pub auto_subscribe: bool,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_float_opt")]
     pub yesterday_airdrop_percentage_rate: Option<f64>,
 
     #[serde(default)]
@@ -1390,8 +1684,11 @@ pub struct LockedProductInfo {
     #[serde(with = "string_or_float")]
     pub amount: f64,
 
+    #[serde(with = "string_or_u64")]
     pub purchase_time: u64,
+    #[serde(with = "string_or_u64")]
     pub duration: u64,
+    #[serde(with = "string_or_u64")]
     pub accrual_days: u64,
     pub reward_asset: String,
 
@@ -1428,23 +1725,23 @@ pub struct LockedProductInfo {
     #[serde(default)]
     pub next_pay: Option<f64>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub next_pay_date: Option<u64>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub pay_period: Option<u64>,
 
     #[serde(rename = "redeemAmountEarly", with = "string_or_float_opt")]
     #[serde(default)]
     pub redeem_amount_early: Option<f64>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub rewards_end_date: Option<u64>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub deliver_date: Option<u64>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub redeem_period: Option<u64>,
 
     #[serde(rename = "redeemingAmt", with = "string_or_float_opt")]
@@ -1454,7 +1751,7 @@ pub struct LockedProductInfo {
     #[serde(default)]
     pub redeem_to: Option<String>,
 
-    #[serde(default)]
+    #[serde(default, with = "string_or_u64_opt")]
     pub partial_amt_deliver_date: Option<u64>,
 
     #[serde(default)]
@@ -1475,74 +1772,1169 @@ pub struct LockedProductInfo {
     pub can_re_stake: Option<bool>,
 }
 
-pub(crate) mod string_or_float {
-    use std::fmt;
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default)]
+    #[serde(with = "string_or_float_hashmap")]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<f64>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    pub product_id: String,
+    pub subscription_start_time: u64,
+    pub status: String,
+}
 
-    use serde::{de, Serializer, Deserialize, Deserializer};
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
 
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// Lock period in days.
+    pub duration: u64,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub status: String,
+    pub subscription_start_time: u64,
+    #[serde(default)]
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<f64>,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
 
-        match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => {
-                if s == "INF" {
-                    Ok(f64::INFINITY)
-                } else {
-                    s.parse().map_err(de::Error::custom)
-                }
-            }
-            StringOrFloat::Float(i) => Ok(i),
+/// Wallet funding a Simple Earn subscription.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnSourceAccount {
+    Spot,
+    Fund,
+    All,
+}
+
+impl fmt::Display for EarnSourceAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::Fund => write!(f, "FUND"),
+            Self::All => write!(f, "ALL"),
         }
     }
 }
 
-pub(crate) mod string_or_float_opt {
-    use std::fmt;
-
-    use serde::{Serializer, Deserialize, Deserializer};
+/// Kind of rewards of a flexible product.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlexibleRewardType {
+    Bonus,
+    Realtime,
+    Rewards,
+}
 
-    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        match value {
-            Some(v) => crate::model::string_or_float::serialize(v, serializer),
-            None => serializer.serialize_none(),
+impl fmt::Display for FlexibleRewardType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bonus => write!(f, "BONUS"),
+            Self::Realtime => write!(f, "REALTIME"),
+            Self::Rewards => write!(f, "REWARDS"),
         }
     }
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[allow(dead_code)]
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnSubscription {
+    pub purchase_id: u64,
+    /// Locked products only.
+    #[serde(default)]
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedemption {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalLeftQuota {
+    #[serde(with = "string_or_float")]
+    pub left_personal_quota: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleSubscriptionRecord {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub purchase_id: u64,
+    #[serde(default)]
+    pub product_id: String,
+    #[serde(rename = "type")]
+    pub subscription_type: String,
+    #[serde(default)]
+    pub source_account: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<f64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSubscriptionRecord {
+    pub position_id: u64,
+    pub purchase_id: u64,
+    #[serde(default)]
+    pub project_id: String,
+    pub time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(rename = "type")]
+    pub subscription_type: String,
+    #[serde(default)]
+    pub source_account: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_spot: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub amt_from_funding: Option<f64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRedemptionRecord {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    #[serde(default)]
+    pub project_id: String,
+    pub redeem_id: u64,
+    #[serde(default)]
+    pub dest_account: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRedemptionRecord {
+    pub position_id: u64,
+    pub redeem_id: u64,
+    pub time: u64,
+    pub asset: String,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub redemption_type: String,
+    #[serde(default)]
+    pub deliver_time: Option<u64>,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRewardRecord {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: f64,
+    #[serde(default)]
+    pub project_id: String,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: u64,
+}
 
-        Ok(Some(crate::model::string_or_float::deserialize(
-            deserializer,
-        )?))
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRewardRecord {
+    pub position_id: u64,
+    pub time: u64,
+    pub asset: String,
+    /// Lock period in days.
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    #[serde(default)]
+    pub details: Vec<DustAsset>,
+    #[serde(with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    /// Share of the converted amount charged as a fee.
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+/// A balance small enough to be converted to BNB.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    /// BNB received after the conversion fee.
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    /// Conversion fee, in BNB.
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    /// BNB received, after the service charge.
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    #[serde(default)]
+    pub transfer_result: Vec<DustTransferResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub from_asset: String,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    pub tran_id: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLog {
+    pub total: usize,
+    #[serde(default)]
+    pub user_asset_dribblets: Vec<DustConversion>,
+}
+
+/// One dust conversion, covering one or more assets.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConversion {
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub total_transfered_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_service_charge_amount: f64,
+    pub trans_id: u64,
+    #[serde(default)]
+    pub user_asset_dribblet_details: Vec<DustConversionDetail>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DustConversionDetail {
+    pub trans_id: u64,
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub operate_time: u64,
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+    pub from_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividend {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub div_time: u64,
+    /// Description of the distribution, e.g. "BNB distribution".
+    pub en_info: String,
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: f64,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub iceberg_qty: Option<f64>,
+    pub is_isolated: bool,
+    pub is_working: bool,
+    pub time: u64,
+    pub update_time: u64,
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrder {
+    pub order_list_id: i64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    #[serde(default)]
+    pub is_isolated: Option<bool>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<f64>,
+    #[serde(default)]
+    pub margin_buy_borrow_asset: Option<String>,
+    pub orders: Vec<OcoOrderEntry>,
+    #[serde(default)]
+    pub order_reports: Vec<Transaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OcoOrderEntry {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Borrow limit of the account's VIP level.
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub principal: Option<f64>,
+    /// PENDING, CONFIRMED or FAILED.
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRecord {
+    pub tx_id: u64,
+    /// Time the interest was charged; Binance's spelling.
+    pub interest_accured_time: u64,
+    pub asset: String,
+    #[serde(default)]
+    pub raw_asset: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: f64,
+    /// PERIODIC, ON_BORROW, PERIODIC_CONVERTED, ON_BORROW_CONVERTED or PORTFOLIO.
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccountInformation {
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    pub trade_enabled: bool,
+    #[serde(default)]
+    pub transfer_in_enabled: Option<bool>,
+    #[serde(default)]
+    pub transfer_out_enabled: Option<bool>,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginPair>,
+    /// Only returned when no symbols are requested.
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_asset_of_btc: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_liability_of_btc: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_net_asset_of_btc: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    pub isolated_created: bool,
+    pub enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    /// EXCESSIVE, NORMAL, MARGIN_CALL, PRE_LIQUIDATION or FORCE_LIQUIDATION.
+    pub margin_level_status: String,
+    #[serde(with = "string_or_float")]
+    pub margin_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_price: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidate_rate: f64,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub net_asset_of_btc: f64,
+    pub repay_enabled: bool,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: Option<bool>,
+    #[serde(default)]
+    pub is_asset_management_sub_account: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubAccountList {
+    #[serde(default)]
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedSubAccount {
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotSummary {
+    pub total_count: usize,
+    /// Spot assets of the master account, in BTC.
+    #[serde(with = "string_or_float")]
+    pub master_account_total_asset: f64,
+    #[serde(rename = "spotSubUserAssetBtcVoList", default)]
+    pub sub_accounts: Vec<SubAccountSpotAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotAsset {
+    pub email: String,
+    /// Spot assets of the sub-account, in BTC.
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+/// USDⓈ-M futures summary of the sub-accounts, in USD.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountUsdmFuturesSummary {
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+    #[serde(default)]
+    pub sub_account_list: Vec<SubAccountUsdmFutures>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountUsdmFutures {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+/// COIN-M futures summary of the sub-accounts, in BTC.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountCoinmFuturesSummary {
+    #[serde(rename = "totalMarginBalanceOfBTC", with = "string_or_float")]
+    pub total_margin_balance_of_btc: f64,
+    #[serde(rename = "totalUnrealizedProfitOfBTC", with = "string_or_float")]
+    pub total_unrealized_profit_of_btc: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", with = "string_or_float")]
+    pub total_wallet_balance_of_btc: f64,
+    pub asset: String,
+    #[serde(default)]
+    pub sub_account_list: Vec<SubAccountCoinmFutures>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountCoinmFutures {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubAccountFuturesSummaryResponse {
+    #[serde(default)]
+    pub future_account_summary_resp: Option<SubAccountUsdmFuturesSummary>,
+    #[serde(default)]
+    pub delivery_account_summary_resp: Option<SubAccountCoinmFuturesSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMarginSummary {
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    #[serde(default)]
+    pub sub_account_list: Vec<SubAccountMargin>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMargin {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferId {
+    pub tran_id: u64,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub create_time_stamp: u64,
+    pub from_account_type: String,
+    pub to_account_type: String,
+    /// PROCESS, SUCCESS or FAILURE.
+    pub status: String,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubAccountTransferHistory {
+    #[serde(default)]
+    pub result: Vec<SubAccountTransfer>,
+    pub total_count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountFuturesEnabled {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountMarginEnabled {
+    pub email: String,
+    pub is_margin_enabled: bool,
+}
+
+/// IP restriction of a sub-account API key.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyIpRestriction {
+    #[serde(with = "string_or_bool")]
+    pub ip_restrict: bool,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+// The v2 update endpoint reports the restriction as `status` ("1" unrestricted,
+// "2" restricted) instead of `ipRestrict`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ApiKeyIpRestrictionUpdate {
+    pub status: String,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+impl From<ApiKeyIpRestrictionUpdate> for ApiKeyIpRestriction {
+    fn from(update: ApiKeyIpRestrictionUpdate) -> Self {
+        ApiKeyIpRestriction {
+            ip_restrict: update.status == "2",
+            ip_list: update.ip_list,
+            update_time: update.update_time,
+            api_key: update.api_key,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPair {
+    pub from_asset: String,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_asset_max_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_max_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertAssetPrecision {
+    pub asset: String,
+    /// Number of decimals accepted for amounts of the asset.
+    pub fraction: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    pub quote_id: String,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    /// Time the quote expires, in milliseconds.
+    pub valid_timestamp: u64,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertAcceptedQuote {
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub create_time: u64,
+    /// PROCESS, ACCEPT_SUCCESS, SUCCESS or FAIL.
+    pub order_status: String,
+}
+
+/// A convert order, as returned by the order status, open limit orders and trade flow
+/// endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    #[serde(default)]
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub order_status: String,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
+    /// Open limit orders only.
+    #[serde(default)]
+    pub expired_timestamp: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertLimitOrder {
+    #[serde(default)]
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    /// PROCESS once placed, CANCELED once cancelled.
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ConvertOrderList {
+    #[serde(default)]
+    pub list: Vec<ConvertOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTradeFlow {
+    #[serde(default)]
+    pub list: Vec<ConvertOrder>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: u32,
+    /// More trades are available in the time range.
+    pub more_data: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub(crate) struct AccountStatus {
+    /// "Normal" unless the account is restricted.
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub(crate) struct ApiTradingStatusResponse {
+    pub data: ApiTradingStatus,
+}
+
+/// Trading status of the API key, locked when the trading rules are breached.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    pub is_locked: bool,
+    /// Time the lock is lifted, 0 if not locked.
+    pub planned_recover_time: u64,
+    /// Thresholds of the trading rules (GCR, IFER, UFR).
+    #[serde(default)]
+    pub trigger_condition: HashMap<String, f64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiRestrictions {
+    pub ip_restrict: bool,
+    pub create_time: u64,
+    pub enable_reading: bool,
+    pub enable_withdrawals: bool,
+    pub enable_internal_transfer: bool,
+    pub enable_margin: bool,
+    pub enable_futures: bool,
+    pub permits_universal_transfer: bool,
+    pub enable_vanilla_options: bool,
+    #[serde(default)]
+    pub enable_fix_api_trade: bool,
+    #[serde(default)]
+    pub enable_fix_read_only: bool,
+    pub enable_spot_and_margin_trading: bool,
+    #[serde(default)]
+    pub enable_portfolio_margin_trading: bool,
+    /// Trading permission of a key without IP restriction expires at this time.
+    #[serde(default)]
+    pub trading_authority_expiration_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccountSnapshots<T> {
+    #[serde(default = "Vec::new")]
+    pub snapshot_vos: Vec<AccountSnapshot<T>>,
+}
+
+/// Daily snapshot of a wallet, taken at `update_time`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshot<T> {
+    #[serde(rename = "type")]
+    pub snapshot_type: String,
+    pub update_time: u64,
+    pub data: T,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSnapshot {
+    pub balances: Vec<Balance>,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSnapshot {
+    #[serde(with = "string_or_float")]
+    pub margin_level: f64,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    pub user_assets: Vec<MarginAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshot {
+    pub assets: Vec<FuturesSnapshotAsset>,
+    #[serde(default)]
+    pub position: Vec<FuturesSnapshotPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: f64,
+}
+
+/// Balance of one asset, as returned by the funding wallet and user asset endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub ipoable: Option<f64>,
+    /// Only returned when the BTC valuation is requested.
+    #[serde(default, with = "string_or_float_opt")]
+    pub btc_valuation: Option<f64>,
+}
+
+pub(crate) mod string_or_float {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        match StringOrFloat::deserialize(deserializer)? {
+            StringOrFloat::String(s) => {
+                if s == "INF" {
+                    Ok(f64::INFINITY)
+                } else {
+                    s.parse().map_err(de::Error::custom)
+                }
+            }
+            StringOrFloat::Float(i) => Ok(i),
+        }
+    }
+}
+
+pub(crate) mod string_or_float_opt {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => crate::model::string_or_float::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        // Binance sends an empty string for values that do not apply
+        match StringOrFloat::deserialize(deserializer)? {
+            StringOrFloat::String(s) if s.is_empty() => Ok(None),
+            StringOrFloat::String(s) => {
+                crate::model::string_or_float::deserialize(de::value::StringDeserializer::new(s))
+                    .map(Some)
+            }
+            StringOrFloat::Float(i) => Ok(Some(i)),
+        }
+    }
+}
+
+pub(crate) mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::U64(i) => Ok(i),
+        }
+    }
+}
+
+pub(crate) mod string_or_u64_opt {
+    use std::fmt;
+
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => crate::model::string_or_u64::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) if s.is_empty() => Ok(None),
+            StringOrU64::String(s) => s.parse().map(Some).map_err(de::Error::custom),
+            StringOrU64::U64(i) => Ok(Some(i)),
+        }
     }
 }
 
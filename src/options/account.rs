@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use crate::util::build_signed_request;
+use crate::errors::Result;
+use crate::client::Client;
+use crate::api::{API, Options};
+use crate::model::Empty;
+use crate::account::OrderSide;
+use crate::futures::account::TimeInForce;
+
+use super::model::{MarginAccount, Order, Position};
+
+/// Account endpoints of the European options.
+///
+/// Options only accept LIMIT orders; quantities are in contracts.
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+pub struct OptionsOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: f64,
+    pub price: f64,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+    pub is_mmp: Option<bool>,
+}
+
+impl OptionsAccount {
+    pub fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(Self::limit_order(
+            symbol.into(),
+            OrderSide::Buy,
+            qty.into(),
+            price,
+            time_in_force,
+        ))
+    }
+
+    pub fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(Self::limit_order(
+            symbol.into(),
+            OrderSide::Sell,
+            qty.into(),
+            price,
+            time_in_force,
+        ))
+    }
+
+    pub fn custom_order(&self, order_request: OptionsOrderRequest) -> Result<Order> {
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Options(Options::Order), request)
+    }
+
+    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_order_with_client_id<S>(
+        &self, symbol: S, client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("clientOrderId".into(), client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Options(Options::AllOpenOrders), Some(request))
+            .map(|_| ())
+    }
+
+    // Open orders of ONE symbol, or of all symbols if `None`
+    pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::OpenOrders), Some(request))
+    }
+
+    // Filled, cancelled and rejected orders of ONE symbol
+    pub fn get_history_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::HistoryOrders), Some(request))
+    }
+
+    // Balances and aggregated Greeks of the options account
+    pub fn account_information(&self) -> Result<MarginAccount> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::MarginAccount), Some(request))
+    }
+
+    // Positions of ONE symbol, or of all symbols if `None`
+    pub fn position_information<S>(&self, symbol: S) -> Result<Vec<Position>>
+    where
+        S: Into<Option<String>>,
+    {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol.into() {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Position), Some(request))
+    }
+
+    fn limit_order(
+        symbol: String, side: OrderSide, qty: f64, price: f64, time_in_force: TimeInForce,
+    ) -> OptionsOrderRequest {
+        OptionsOrderRequest {
+            symbol,
+            side,
+            qty,
+            price,
+            time_in_force: Some(time_in_force),
+            reduce_only: None,
+            post_only: None,
+            client_order_id: None,
+            is_mmp: None,
+        }
+    }
+}
+
+fn build_order(order: OptionsOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), "LIMIT".into());
+    parameters.insert("quantity".into(), order.qty.to_string());
+    parameters.insert("price".into(), order.price.to_string());
+
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(reduce_only) = order.reduce_only {
+        parameters.insert("reduceOnly".into(), reduce_only.to_string());
+    }
+    if let Some(post_only) = order.post_only {
+        parameters.insert("postOnly".into(), post_only.to_string());
+    }
+    if let Some(client_order_id) = order.client_order_id {
+        parameters.insert("clientOrderId".into(), client_order_id);
+    }
+    if let Some(is_mmp) = order.is_mmp {
+        parameters.insert("isMmp".into(), is_mmp.to_string());
+    }
+
+    parameters
+}
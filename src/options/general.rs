@@ -0,0 +1,41 @@
+use crate::options::model::{ExchangeInformation, OptionSymbol, ServerTime};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::futures::general::{find_symbol, ping};
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsGeneral {
+    pub client: Client,
+}
+
+impl OptionsGeneral {
+    // Test connectivity
+    pub fn ping(&self) -> Result<String> {
+        ping(&self.client, API::Options(Options::Ping))
+    }
+
+    // Check server time
+    pub fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::Options(Options::Time), None)
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and symbol information
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get(API::Options(Options::ExchangeInfo), None)
+    }
+
+    // Get Symbol information
+    pub fn get_symbol_info<S>(&self, symbol: S) -> Result<OptionSymbol>
+    where
+        S: Into<String>,
+    {
+        find_symbol(
+            self.exchange_info()?.option_symbols,
+            symbol.into(),
+            |item| &item.symbol,
+        )
+    }
+}
@@ -0,0 +1,128 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Kline/Candlestick Data`
+- [x] `Option Mark Price`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Historical Exercise Records`
+*/
+
+use crate::util::build_request;
+use crate::options::model::{ExerciseRecord, Kline, MarkPrice, OrderBook, Ticker};
+use crate::futures::market::{get_depth, get_for_symbol, get_klines, insert_range};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsMarket {
+    // Order book (Default 100; max 1000)
+    pub fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        get_depth(
+            &self.client,
+            API::Options(Options::Depth),
+            symbol.into(),
+            None,
+        )
+    }
+
+    // Order book at a custom depth. Currently supported values
+    // are 10, 20, 50, 100, 500, 1000
+    pub fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        get_depth(
+            &self.client,
+            API::Options(Options::Depth),
+            symbol.into(),
+            Some(depth),
+        )
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        get_klines(
+            &self.client,
+            API::Options(Options::Klines),
+            parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        )
+    }
+
+    // Mark price and Greeks of ONE symbol
+    pub fn get_mark_price<S>(&self, symbol: S) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(&self.client, API::Options(Options::Mark), symbol.into())
+    }
+
+    // Mark price and Greeks of all symbols
+    pub fn get_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get(API::Options(Options::Mark), None)
+    }
+
+    // 24hr ticker price change statistics
+    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<Ticker>>
+    where
+        S: Into<String>,
+    {
+        get_for_symbol(&self.client, API::Options(Options::Ticker), symbol.into())
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub fn get_all_24h_price_stats(&self) -> Result<Vec<Ticker>> {
+        self.client.get(API::Options(Options::Ticker), None)
+    }
+
+    // Exercise records of expired options, optionally for ONE underlying ("BTCUSDT", ...)
+    pub fn get_exercise_history<S1, S2, S3, S4>(
+        &self, underlying: S1, start_time: S2, end_time: S3, limit: S4,
+    ) -> Result<Vec<ExerciseRecord>>
+    where
+        S1: Into<Option<String>>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(underlying) = underlying.into() {
+            parameters.insert("underlying".into(), underlying);
+        }
+        insert_range(
+            &mut parameters,
+            start_time.into(),
+            end_time.into(),
+            limit.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Options::ExerciseHistory), Some(request))
+    }
+}
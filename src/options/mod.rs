@@ -0,0 +1,11 @@
+//! European options, served from `eapi.binance.com`.
+//!
+//! Option symbols follow the `<underlying>-<yyMMdd>-<strike>-<C|P>` format
+//! (e.g. `BTC-240628-60000-C`); strike, expiry and call/put are also exposed
+//! as typed fields on the models.
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod model;
+pub mod userstream;
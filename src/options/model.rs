@@ -0,0 +1,324 @@
+use serde::{Deserialize, Serialize};
+use crate::model::string_or_float;
+
+pub use crate::futures::model::{Asks, Bids, Filters, RateLimit, ServerTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub underlying: String,
+    pub quote_asset: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    /// Expiry timestamp in milliseconds.
+    pub expiry_date: u64,
+    /// Quantity of underlying covered by one contract.
+    pub unit: u64,
+    pub filters: Vec<Filters>,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: f64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+}
+
+impl OptionSymbol {
+    pub fn is_call(&self) -> bool {
+        self.side == OptionSide::Call
+    }
+
+    pub fn is_put(&self) -> bool {
+        self.side == OptionSide::Put
+    }
+
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        now_ms >= self.expiry_date
+    }
+
+    /// Value of one contract if exercised at the given underlying price.
+    pub fn intrinsic_value(&self, underlying_price: f64) -> f64 {
+        let per_unit = match self.side {
+            OptionSide::Call => underlying_price - self.strike_price,
+            OptionSide::Put => self.strike_price - underlying_price,
+        };
+        per_unit.max(0.0) * self.unit as f64
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub interval: String,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+}
+
+/// Mark price, implied volatilities and Greeks of an option.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float", rename = "bidIV")]
+    pub bid_iv: f64,
+    #[serde(with = "string_or_float", rename = "askIV")]
+    pub ask_iv: f64,
+    #[serde(with = "string_or_float", rename = "markIV")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_price: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_trade_id: u64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub exercise_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecord {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub real_strike_price: f64,
+    pub expiry_date: u64,
+    /// `REALISTIC_VALUE_STRICKEN` (in the money) or `EXTRINSIC_VALUE_EXPIRED`.
+    pub strike_result: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    pub asset: Vec<MarginAsset>,
+    pub greek: Vec<Greek>,
+    pub time: u64,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub reduce_only: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub equity: f64,
+    #[serde(with = "string_or_float")]
+    pub available: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub adjusted_equity: f64,
+}
+
+/// Aggregated Greeks of the account for one underlying.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Greek {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    pub side: PositionSide,
+    pub option_side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub expiry_date: u64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_value: f64,
+    #[serde(with = "string_or_float")]
+    pub ror: f64,
+    #[serde(with = "string_or_float", rename = "unrealizedPNL")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_cost: f64,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub time_in_force: String,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(default)]
+    pub client_order_id: String,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub mmp: bool,
+}
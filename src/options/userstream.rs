@@ -0,0 +1,35 @@
+use crate::model::{Success, UserDataStream};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::futures::userstream::{close, keep_alive, start};
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsUserStream {
+    // User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        start(&self.client, API::Options(Options::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        keep_alive(
+            &self.client,
+            API::Options(Options::UserDataStream),
+            listen_key,
+        )
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        close(
+            &self.client,
+            API::Options(Options::UserDataStream),
+            listen_key,
+        )
+    }
+}
@@ -0,0 +1,353 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, VecDeque};
+use std::time::{Duration, Instant};
+use crate::errors::{Result, SdkError};
+use crate::futures::market::FuturesMarket;
+use crate::market::Market;
+use crate::model::{Asks, Bids, DepthOrderBookEvent};
+
+// Price of an order book level, ordered numerically
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Price(f64);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+// Where the REST snapshots come from, which also decides the sync procedure
+enum SnapshotSource {
+    Spot(Market),
+    UsdmFutures(FuturesMarket),
+}
+
+// Wait before fetching another snapshot when the last one was older than the stream
+const STALE_SNAPSHOT_DELAY: Duration = Duration::from_millis(500);
+// Snapshots in a row older than the stream before giving up on syncing
+const MAX_STALE_SNAPSHOTS: u32 = 5;
+// Events kept while waiting for a snapshot, the oldest are dropped beyond
+const MAX_BUFFERED_EVENTS: usize = 1000;
+
+type UpdateCallback<'a> = Box<dyn FnMut(&LocalOrderBook<'a>) + 'a>;
+
+struct Snapshot {
+    last_update_id: u64,
+    bids: Vec<Bids>,
+    asks: Vec<Asks>,
+}
+
+/// Order book of one symbol, kept up to date from a REST snapshot and the diff depth stream.
+///
+/// Feed it every `DepthOrderBookEvent` of the symbol with `apply`. It buffers the events
+/// until a snapshot lines up with them, following Binance's sync procedure for spot, or
+/// the `pu` chain for USDⓈ-M futures, and fetches a new snapshot whenever an update is
+/// missed. A snapshot older than the buffered events is retried after a short wait, and
+/// `apply` fails once 5 snapshots in a row were too old.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::market::*;
+/// use binance::order_book::*;
+/// use binance::websockets::*;
+/// use std::sync::atomic::AtomicBool;
+///
+/// let market: Market = Binance::new(None, None);
+/// let mut book = LocalOrderBook::spot(market, "BTCUSDT", 1000);
+/// book.on_update(|book| println!("{:?} {:?}", book.best_bid(), book.best_ask()));
+///
+/// let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+///     if let WebsocketEvent::DepthOrderBook(depth) = event {
+///         book.apply(depth)?;
+///     }
+///     Ok(())
+/// });
+/// web_socket.connect("btcusdt@depth@100ms").unwrap();
+/// web_socket.event_loop(&AtomicBool::new(true)).unwrap();
+/// ```
+pub struct LocalOrderBook<'a> {
+    symbol: String,
+    depth: u64,
+    source: SnapshotSource,
+    bids: BTreeMap<Reverse<Price>, f64>,
+    asks: BTreeMap<Price, f64>,
+    last_update_id: u64,
+    synced: bool,
+    // Events received before the book is synced, and the snapshot they are waiting for
+    buffer: VecDeque<DepthOrderBookEvent>,
+    snapshot: Option<Snapshot>,
+    // Snapshots in a row older than the buffered events, and when to fetch the next one
+    stale_snapshots: u32,
+    next_fetch: Option<Instant>,
+    resyncs: u64,
+    on_update: Option<UpdateCallback<'a>>,
+}
+
+impl<'a> LocalOrderBook<'a> {
+    /// Spot order book, synced from snapshots of `depth` levels per side (up to 5000).
+    pub fn spot<S: Into<String>>(market: Market, symbol: S, depth: u64) -> Self {
+        Self::new(SnapshotSource::Spot(market), symbol.into(), depth)
+    }
+
+    /// USDⓈ-M futures order book, synced from snapshots of `depth` levels per side (up to 1000).
+    pub fn usdm_futures<S: Into<String>>(market: FuturesMarket, symbol: S, depth: u64) -> Self {
+        Self::new(SnapshotSource::UsdmFutures(market), symbol.into(), depth)
+    }
+
+    fn new(source: SnapshotSource, symbol: String, depth: u64) -> Self {
+        LocalOrderBook {
+            symbol,
+            depth,
+            source,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            last_update_id: 0,
+            synced: false,
+            buffer: VecDeque::new(),
+            snapshot: None,
+            stale_snapshots: 0,
+            next_fetch: None,
+            resyncs: 0,
+            on_update: None,
+        }
+    }
+
+    /// Called after each update applied to a synced book.
+    pub fn on_update<F>(&mut self, callback: F)
+    where
+        F: FnMut(&LocalOrderBook<'a>) + 'a,
+    {
+        self.on_update = Some(Box::new(callback));
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Whether the book reflects the stream; queries return nothing until it does.
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Final update id of the last applied event.
+    pub fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    /// How many times a missed update forced the book to sync again.
+    pub fn resyncs(&self) -> u64 {
+        self.resyncs
+    }
+
+    /// Apply a diff depth event of the symbol, fetching a snapshot if the book is not synced.
+    ///
+    /// Events of other symbols are ignored.
+    pub fn apply(&mut self, event: DepthOrderBookEvent) -> Result<()> {
+        if !event.symbol.eq_ignore_ascii_case(&self.symbol) {
+            return Ok(());
+        }
+        if !self.synced {
+            if self.buffer.len() == MAX_BUFFERED_EVENTS {
+                self.buffer.pop_front();
+            }
+            self.buffer.push_back(event);
+            return self.sync();
+        }
+        if event.final_update_id <= self.last_update_id {
+            return Ok(());
+        }
+        if !self.follows(&event) {
+            self.resyncs += 1;
+            self.reset();
+            self.buffer.push_back(event);
+            return self.sync();
+        }
+        self.update(&event);
+        self.notify();
+        Ok(())
+    }
+
+    pub fn best_bid(&self) -> Option<Bids> {
+        self.bids(1).pop()
+    }
+
+    pub fn best_ask(&self) -> Option<Asks> {
+        self.asks(1).pop()
+    }
+
+    /// Best `levels` bids, highest price first.
+    pub fn bids(&self, levels: usize) -> Vec<Bids> {
+        (self.bids.iter())
+            .take(levels)
+            .map(|(price, qty)| Bids::new(price.0 .0, *qty))
+            .collect()
+    }
+
+    /// Best `levels` asks, lowest price first.
+    pub fn asks(&self, levels: usize) -> Vec<Asks> {
+        (self.asks.iter())
+            .take(levels)
+            .map(|(price, qty)| Asks {
+                price: price.0,
+                qty: *qty,
+            })
+            .collect()
+    }
+
+    /// Quantity bid at exactly `price`, if any.
+    pub fn bid_qty_at(&self, price: f64) -> Option<f64> {
+        self.bids.get(&Reverse(Price(price))).copied()
+    }
+
+    /// Quantity asked at exactly `price`, if any.
+    pub fn ask_qty_at(&self, price: f64) -> Option<f64> {
+        self.asks.get(&Price(price)).copied()
+    }
+
+    // Whether the event comes right after the last applied one
+    fn follows(&self, event: &DepthOrderBookEvent) -> bool {
+        match self.source {
+            SnapshotSource::Spot(_) => event.first_update_id <= self.last_update_id + 1,
+            SnapshotSource::UsdmFutures(_) => {
+                event.previous_final_update_id == Some(self.last_update_id)
+            }
+        }
+    }
+
+    // Whether the first event to apply on top of a snapshot covers its last update id
+    fn straddles(&self, event: &DepthOrderBookEvent, last_update_id: u64) -> bool {
+        match self.source {
+            SnapshotSource::Spot(_) => {
+                event.first_update_id <= last_update_id + 1
+                    && event.final_update_id > last_update_id
+            }
+            SnapshotSource::UsdmFutures(_) => {
+                event.first_update_id <= last_update_id && event.final_update_id >= last_update_id
+            }
+        }
+    }
+
+    // Loads a snapshot and the buffered events after it once they line up
+    fn sync(&mut self) -> Result<()> {
+        let snapshot = match self.snapshot.take() {
+            Some(snapshot) => snapshot,
+            None if self.next_fetch.is_some_and(|next| Instant::now() < next) => return Ok(()),
+            None => self.fetch_snapshot()?,
+        };
+        let last_update_id = snapshot.last_update_id;
+        self.buffer.retain(|event| match self.source {
+            SnapshotSource::Spot(_) => event.final_update_id > last_update_id,
+            SnapshotSource::UsdmFutures(_) => event.final_update_id >= last_update_id,
+        });
+        let Some(first) = self.buffer.front() else {
+            // Every buffered event is older than the snapshot, wait for the next ones
+            self.snapshot = Some(snapshot);
+            return Ok(());
+        };
+        if !self.straddles(first, last_update_id) {
+            // The snapshot is older than the buffered events, take a new one after a while
+            self.stale_snapshots += 1;
+            self.next_fetch = Some(Instant::now() + STALE_SNAPSHOT_DELAY);
+            if self.stale_snapshots == MAX_STALE_SNAPSHOTS {
+                self.stale_snapshots = 0;
+                return Err(SdkError::Other(format!(
+                    "{} snapshots of {} in a row were older than the depth stream",
+                    MAX_STALE_SNAPSHOTS, self.symbol
+                )));
+            }
+            return Ok(());
+        }
+        self.stale_snapshots = 0;
+        self.next_fetch = None;
+
+        self.bids.clear();
+        self.asks.clear();
+        for bid in snapshot.bids {
+            self.bids.insert(Reverse(Price(bid.price)), bid.qty);
+        }
+        for ask in snapshot.asks {
+            self.asks.insert(Price(ask.price), ask.qty);
+        }
+        let buffer = std::mem::take(&mut self.buffer);
+        let mut events = buffer.into_iter();
+        if let Some(first) = events.next() {
+            self.update(&first);
+        }
+        self.synced = true;
+        for event in events {
+            if !self.follows(&event) {
+                self.resyncs += 1;
+                self.reset();
+                self.buffer.push_back(event);
+                return self.sync();
+            }
+            self.update(&event);
+        }
+        self.notify();
+        Ok(())
+    }
+
+    fn fetch_snapshot(&self) -> Result<Snapshot> {
+        match &self.source {
+            SnapshotSource::Spot(market) => {
+                let book = market.get_custom_depth(self.symbol.as_str(), self.depth)?;
+                Ok(Snapshot {
+                    last_update_id: book.last_update_id,
+                    bids: book.bids,
+                    asks: book.asks,
+                })
+            }
+            SnapshotSource::UsdmFutures(market) => {
+                let book = market.get_custom_depth(self.symbol.as_str(), self.depth)?;
+                Ok(Snapshot {
+                    last_update_id: book.last_update_id,
+                    bids: book.bids,
+                    asks: book.asks,
+                })
+            }
+        }
+    }
+
+    fn update(&mut self, event: &DepthOrderBookEvent) {
+        for bid in &event.bids {
+            if bid.qty == 0.0 {
+                self.bids.remove(&Reverse(Price(bid.price)));
+            } else {
+                self.bids.insert(Reverse(Price(bid.price)), bid.qty);
+            }
+        }
+        for ask in &event.asks {
+            if ask.qty == 0.0 {
+                self.asks.remove(&Price(ask.price));
+            } else {
+                self.asks.insert(Price(ask.price), ask.qty);
+            }
+        }
+        self.last_update_id = event.final_update_id;
+    }
+
+    fn reset(&mut self) {
+        self.synced = false;
+        self.bids.clear();
+        self.asks.clear();
+        self.buffer.clear();
+        self.snapshot = None;
+        self.stale_snapshots = 0;
+        self.next_fetch = None;
+    }
+
+    fn notify(&mut self) {
+        if let Some(mut callback) = self.on_update.take() {
+            callback(self);
+            self.on_update = Some(callback);
+        }
+    }
+}
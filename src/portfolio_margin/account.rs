@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use serde::de::DeserializeOwned;
+use crate::util::build_signed_request;
+use crate::errors::{Result, SdkError};
+use crate::client::Client;
+use crate::api::{API, Papi};
+use crate::model::{Empty, Success, UserDataStream};
+use crate::futures::account::{build_order, CustomOrderRequest, OrderType};
+
+use super::model::{
+    AccountInformation, Balance, BalanceResponse, ChangeLeverageResponse, ConditionalOrder, Order,
+    PositionRisk, TransactionId,
+};
+
+/// Client of a unified account in Portfolio Margin mode, served from `papi.binance.com`.
+///
+/// UM (USDⓈ-M) and CM (COIN-M) orders are built with the futures `CustomOrderRequest`.
+/// LIMIT and MARKET orders go through `um_custom_order` / `cm_custom_order`, all other
+/// types are conditional orders placed with `um_conditional_order` / `cm_conditional_order`.
+#[derive(Clone)]
+pub struct PortfolioMargin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl PortfolioMargin {
+    // LIMIT or MARKET USDⓈ-M order
+    pub fn um_custom_order(&self, order_request: CustomOrderRequest) -> Result<Order> {
+        self.place_order(Papi::UmOrder, order_request)
+    }
+
+    // LIMIT or MARKET COIN-M order
+    pub fn cm_custom_order(&self, order_request: CustomOrderRequest) -> Result<Order> {
+        self.place_order(Papi::CmOrder, order_request)
+    }
+
+    // Stop, take profit or trailing stop USDⓈ-M order
+    pub fn um_conditional_order(
+        &self, order_request: CustomOrderRequest,
+    ) -> Result<ConditionalOrder> {
+        self.place_conditional_order(Papi::UmConditionalOrder, order_request)
+    }
+
+    // Stop or take profit COIN-M order
+    pub fn cm_conditional_order(
+        &self, order_request: CustomOrderRequest,
+    ) -> Result<ConditionalOrder> {
+        self.place_conditional_order(Papi::CmConditionalOrder, order_request)
+    }
+
+    pub fn um_order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.get_order(Papi::UmOrder, symbol.into(), order_id)
+    }
+
+    pub fn cm_order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.get_order(Papi::CmOrder, symbol.into(), order_id)
+    }
+
+    pub fn um_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.cancel_order(Papi::UmOrder, symbol.into(), "orderId", order_id)
+    }
+
+    pub fn cm_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        self.cancel_order(Papi::CmOrder, symbol.into(), "orderId", order_id)
+    }
+
+    pub fn um_cancel_conditional_order<S>(
+        &self, symbol: S, strategy_id: u64,
+    ) -> Result<ConditionalOrder>
+    where
+        S: Into<String>,
+    {
+        self.cancel_order(
+            Papi::UmConditionalOrder,
+            symbol.into(),
+            "strategyId",
+            strategy_id,
+        )
+    }
+
+    pub fn cm_cancel_conditional_order<S>(
+        &self, symbol: S, strategy_id: u64,
+    ) -> Result<ConditionalOrder>
+    where
+        S: Into<String>,
+    {
+        self.cancel_order(
+            Papi::CmConditionalOrder,
+            symbol.into(),
+            "strategyId",
+            strategy_id,
+        )
+    }
+
+    pub fn um_cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.cancel_all_open_orders(Papi::UmAllOpenOrders, symbol.into())
+    }
+
+    pub fn cm_cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.cancel_all_open_orders(Papi::CmAllOpenOrders, symbol.into())
+    }
+
+    // Open orders of ONE symbol, or of all symbols if `None`
+    pub fn um_get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.get_with_optional(Papi::UmOpenOrders, "symbol", symbol.into())
+    }
+
+    // Open orders of ONE symbol, or of all symbols if `None`
+    pub fn cm_get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.get_with_optional(Papi::CmOpenOrders, "symbol", symbol.into())
+    }
+
+    // Positions of ONE symbol, or of all symbols if `None`
+    pub fn um_position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.get_with_optional(Papi::UmPositionRisk, "symbol", symbol.into())
+    }
+
+    // Positions of ONE pair ("BTCUSD", ...), or of all pairs if `None`
+    pub fn cm_position_information<S>(&self, pair: S) -> Result<Vec<PositionRisk>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.get_with_optional(Papi::CmPositionRisk, "pair", pair.into())
+    }
+
+    pub fn um_change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        self.change_initial_leverage(Papi::UmLeverage, symbol.into(), leverage)
+    }
+
+    pub fn cm_change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        self.change_initial_leverage(Papi::CmLeverage, symbol.into(), leverage)
+    }
+
+    // Borrow on the cross margin account
+    pub fn margin_borrow<S, F>(&self, asset: S, amount: F) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.margin_loan(Papi::MarginLoan, asset.into(), amount.into())
+    }
+
+    // Repay a cross margin loan
+    pub fn margin_repay<S, F>(&self, asset: S, amount: F) -> Result<TransactionId>
+    where
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        self.margin_loan(Papi::RepayLoan, asset.into(), amount.into())
+    }
+
+    // Balance of ONE asset, or of all assets if `None`
+    pub fn get_balance<S>(&self, asset: S) -> Result<Vec<Balance>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.get_with_optional::<BalanceResponse>(Papi::Balance, "asset", asset.into())
+            .map(Vec::from)
+    }
+
+    pub fn account_information(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::PortfolioMargin(Papi::Account), Some(request))
+    }
+
+    // Collect the assets of the UM and CM wallets back to the margin wallet
+    pub fn fund_auto_collection(&self) -> Result<()> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::PortfolioMargin(Papi::AutoCollection), request)
+            .map(|_| ())
+    }
+
+    // Collect ONE asset of the UM and CM wallets back to the margin wallet
+    pub fn fund_collection_by_asset<S>(&self, asset: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::PortfolioMargin(Papi::AssetCollection), request)
+            .map(|_| ())
+    }
+
+    // User Stream
+    pub fn start_user_stream(&self) -> Result<UserDataStream> {
+        self.client.post(API::PortfolioMargin(Papi::UserDataStream))
+    }
+
+    pub fn keep_alive_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::PortfolioMargin(Papi::UserDataStream), listen_key)
+    }
+
+    pub fn close_user_stream(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::PortfolioMargin(Papi::UserDataStream), listen_key)
+    }
+
+    fn place_order(&self, route: Papi, order_request: CustomOrderRequest) -> Result<Order> {
+        if !matches!(
+            order_request.order_type,
+            OrderType::Limit | OrderType::Market
+        ) {
+            return Err(SdkError::Other(format!(
+                "{} is a conditional order type, use the conditional order endpoint",
+                order_request.order_type
+            )));
+        }
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::PortfolioMargin(route), request)
+    }
+
+    fn place_conditional_order(
+        &self, route: Papi, order_request: CustomOrderRequest,
+    ) -> Result<ConditionalOrder> {
+        if matches!(
+            order_request.order_type,
+            OrderType::Limit | OrderType::Market
+        ) {
+            return Err(SdkError::Other(format!(
+                "{} is not a conditional order type",
+                order_request.order_type
+            )));
+        }
+        let order = build_conditional_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::PortfolioMargin(route), request)
+    }
+
+    fn get_order(&self, route: Papi, symbol: String, order_id: u64) -> Result<Order> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol);
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::PortfolioMargin(route), Some(request))
+    }
+
+    fn cancel_order<T: DeserializeOwned>(
+        &self, route: Papi, symbol: String, id_name: &str, id: u64,
+    ) -> Result<T> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol);
+        parameters.insert(id_name.into(), id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::PortfolioMargin(route), Some(request))
+    }
+
+    fn cancel_all_open_orders(&self, route: Papi, symbol: String) -> Result<()> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::PortfolioMargin(route), Some(request))
+            .map(|_| ())
+    }
+
+    fn change_initial_leverage(
+        &self, route: Papi, symbol: String, leverage: u8,
+    ) -> Result<ChangeLeverageResponse> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol);
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::PortfolioMargin(route), request)
+    }
+
+    fn margin_loan(&self, route: Papi, asset: String, amount: f64) -> Result<TransactionId> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset);
+        parameters.insert("amount".into(), amount.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::PortfolioMargin(route), request)
+    }
+
+    fn get_with_optional<T: DeserializeOwned>(
+        &self, route: Papi, name: &str, value: Option<String>,
+    ) -> Result<T> {
+        let mut parameters = BTreeMap::new();
+        if let Some(value) = value {
+            parameters.insert(name.into(), value);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::PortfolioMargin(route), Some(request))
+    }
+}
+
+// Conditional orders take the order type as `strategyType` and the client id
+// as `newClientStrategyId`
+fn build_conditional_order(order: CustomOrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = build_order(order);
+    if let Some(order_type) = parameters.remove("type") {
+        parameters.insert("strategyType".into(), order_type);
+    }
+    if let Some(client_id) = parameters.remove("newClientOrderId") {
+        parameters.insert("newClientStrategyId".into(), client_id);
+    }
+    parameters
+}
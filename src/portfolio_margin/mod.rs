@@ -0,0 +1,10 @@
+//! Portfolio Margin (unified account), served from `papi.binance.com`.
+//!
+//! A single `PortfolioMargin` client trades USDⓈ-M (UM) and COIN-M (CM) futures,
+//! borrows and repays on the cross margin account and manages the user data stream
+//! of the unified account. The user data stream is served from
+//! `Config::portfolio_margin_ws_endpoint`; connect to it with `FuturesWebSockets` and
+//! `FuturesMarket::PortfolioMargin`, passing the listen key as the stream.
+
+pub mod account;
+pub mod model;
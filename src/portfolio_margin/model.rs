@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt};
+
+pub use crate::model::TransactionId;
+
+/// A regular (LIMIT or MARKET) UM or CM order.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: f64,
+    /// Executed amount in quote asset, UM orders only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_quote: Option<f64>,
+    /// Executed amount in base asset, CM orders only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_base: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    pub symbol: String,
+    /// CM orders only.
+    #[serde(default)]
+    pub pair: Option<String>,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(default)]
+    pub good_till_date: Option<u64>,
+    #[serde(default)]
+    pub price_match: Option<String>,
+    pub update_time: u64,
+}
+
+/// A conditional (stop, take profit or trailing stop) UM or CM order.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionalOrder {
+    pub new_client_strategy_id: String,
+    pub strategy_id: u64,
+    pub strategy_status: String,
+    pub strategy_type: String,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub stop_price: Option<f64>,
+    pub symbol: String,
+    /// CM orders only.
+    #[serde(default)]
+    pub pair: Option<String>,
+    pub time_in_force: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<f64>,
+    pub book_time: u64,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+    #[serde(default)]
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(default)]
+    pub good_till_date: Option<u64>,
+    #[serde(default)]
+    pub price_match: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    pub leverage: String,
+    pub position_side: String,
+    /// UM positions only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub max_notional_value: Option<f64>,
+    /// UM positions only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub notional: Option<f64>,
+    /// CM positions only, in contracts.
+    #[serde(default, with = "string_or_float_opt")]
+    pub max_qty: Option<f64>,
+    /// CM positions only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub notional_value: Option<f64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    /// UM symbols only.
+    #[serde(default, with = "string_or_float_opt")]
+    pub max_notional_value: Option<f64>,
+    /// CM symbols only, in contracts.
+    #[serde(default, with = "string_or_float_opt")]
+    pub max_qty: Option<f64>,
+    pub symbol: String,
+}
+
+/// Balance of one asset across the margin, UM and CM wallets.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_free: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_locked: f64,
+    #[serde(with = "string_or_float")]
+    pub um_wallet_balance: f64,
+    #[serde(default, with = "string_or_float_opt", rename = "umUnrealizedPNL")]
+    pub um_unrealized_pnl: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub cm_wallet_balance: f64,
+    #[serde(default, with = "string_or_float_opt", rename = "cmUnrealizedPNL")]
+    pub cm_unrealized_pnl: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub negative_balance: Option<f64>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub(crate) enum BalanceResponse {
+    Many(Vec<Balance>),
+    One(Balance),
+}
+
+impl From<BalanceResponse> for Vec<Balance> {
+    fn from(response: BalanceResponse) -> Self {
+        match response {
+            BalanceResponse::Many(balances) => balances,
+            BalanceResponse::One(balance) => vec![balance],
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    /// Unified maintenance margin ratio.
+    #[serde(rename = "uniMMR", with = "string_or_float")]
+    pub uni_mmr: f64,
+    #[serde(with = "string_or_float")]
+    pub account_equity: f64,
+    #[serde(with = "string_or_float")]
+    pub actual_equity: f64,
+    #[serde(with = "string_or_float")]
+    pub account_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub account_maint_margin: f64,
+    /// NORMAL, MARGIN_CALL, SUPPLY_MARGIN, REDUCE_ONLY, ACTIVE_LIQUIDATION,
+    /// FORCE_LIQUIDATION or BANKRUPTED.
+    pub account_status: String,
+    #[serde(with = "string_or_float")]
+    pub virtual_max_withdraw_amount: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_available_balance: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_margin_open_loss: Option<f64>,
+    pub update_time: u64,
+}
@@ -1,20 +1,149 @@
-use crate::util::build_signed_request;
+use crate::util::{build_signed_request, encode_param};
 use crate::model::{
-    AssetDetail, CoinInfo, DepositAddress, FlexibleProductInfo, LockedProductInfo,
-    PaginatedResponse, SpotFuturesTransferType, TransactionId,
+    AssetDetail, AssetDividend, CoinInfo, DepositAddress, DepositAddressInfo, DepositRecord,
+    DustAsset, DustAssets, DustLog, DustTransfer, EarnRedemption, EarnSourceAccount,
+    EarnSubscription, FlexibleProduct, FlexibleProductInfo, FlexibleRedemptionRecord,
+    FlexibleRewardRecord, FlexibleRewardType, FlexibleSubscriptionRecord, LockedProduct,
+    LockedProductInfo, LockedRedemptionRecord, LockedRewardRecord, LockedSubscriptionRecord,
+    PaginatedResponse, PersonalLeftQuota, Prices, TransactionId, UniversalTransfer,
+    UniversalTransferType, WithdrawRecord, WithdrawResponse,
 };
 use crate::client::Client;
-use crate::errors::Result;
-use std::collections::BTreeMap;
+use crate::errors::{Result, SdkError};
+use crate::market::Market;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use serde::de::DeserializeOwned;
 use crate::api::API;
 use crate::api::Sapi;
 
+// Largest page of the paginated Simple Earn and transfer endpoints
+const PAGE_SIZE: u32 = 100;
+
 #[derive(Clone)]
 pub struct Savings {
     pub client: Client,
     pub recv_window: u64,
 }
 
+pub struct TransferHistoryRequest {
+    pub transfer_type: UniversalTransferType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1.
+    pub current: Option<u32>,
+    /// Rows per page, at most 100.
+    pub size: Option<u32>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+}
+
+pub struct WithdrawRequest {
+    pub coin: String,
+    /// Default network of the coin if `None`.
+    pub network: Option<String>,
+    pub address: String,
+    /// Memo or tag of the address.
+    pub address_tag: Option<String>,
+    pub amount: f64,
+    /// Client id of the withdrawal.
+    pub withdraw_order_id: Option<String>,
+    /// When true the fee is deducted from `amount`, otherwise it is charged on top.
+    pub transaction_fee_flag: Option<bool>,
+    /// Description of the address, saved in the address book.
+    pub name: Option<String>,
+}
+
+impl WithdrawRequest {
+    pub fn new<S1, S2>(coin: S1, address: S2, amount: f64) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        WithdrawRequest {
+            coin: coin.into(),
+            network: None,
+            address: address.into(),
+            address_tag: None,
+            amount,
+            withdraw_order_id: None,
+            transaction_fee_flag: None,
+            name: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DepositHistoryRequest {
+    pub coin: Option<String>,
+    pub status: Option<u8>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub offset: Option<u32>,
+    /// At most 1000.
+    pub limit: Option<u32>,
+    pub tx_id: Option<String>,
+}
+
+#[derive(Default)]
+pub struct WithdrawHistoryRequest {
+    pub coin: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub status: Option<u8>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub offset: Option<u32>,
+    /// At most 1000.
+    pub limit: Option<u32>,
+}
+
+/// Wallet holding the dust to convert.
+#[derive(Clone, Copy)]
+pub enum DustAccountType {
+    Spot,
+    Margin,
+}
+
+impl Display for DustAccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::Margin => write!(f, "MARGIN"),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DividendHistoryRequest {
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// At most 500.
+    pub limit: Option<u32>,
+}
+
+/// Filters of the Simple Earn history endpoints, which cover at most 3 months per query.
+#[derive(Default, Clone)]
+pub struct EarnHistoryRequest {
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+impl TransferHistoryRequest {
+    pub fn new(transfer_type: UniversalTransferType) -> Self {
+        TransferHistoryRequest {
+            transfer_type,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            from_symbol: None,
+            to_symbol: None,
+        }
+    }
+}
+
 impl Savings {
     /// Get all coins available for deposit and withdrawal
     pub fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
@@ -52,30 +181,580 @@ impl Savings {
             .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
     }
 
+    /// Fetch all deposit addresses of a coin on one network, or on the default network.
+    pub fn deposit_addresses<S>(
+        &self, coin: S, network: Option<String>,
+    ) -> Result<Vec<DepositAddressInfo>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("coin".into(), coin.into());
+        if let Some(network) = network {
+            parameters.insert("network".into(), network);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositAddresses), Some(request))
+    }
+
+    /// Submit a withdrawal.
+    ///
+    /// The request is first checked against the rules of the selected network from
+    /// `get_all_coins`, see `Network::validate_withdrawal`.
+    pub fn withdraw(&self, withdraw_request: WithdrawRequest) -> Result<WithdrawResponse> {
+        let coins = self.get_all_coins()?;
+        let coin = coins
+            .iter()
+            .find(|coin| coin.coin == withdraw_request.coin)
+            .ok_or_else(|| SdkError::Other(format!("Unknown coin {}", withdraw_request.coin)))?;
+        self.withdraw_with_coin_info(withdraw_request, coin)
+    }
+
+    /// Submit a withdrawal, checked against a `CoinInfo` the caller already fetched
+    /// with `get_all_coins`, e.g. to withdraw several times without fetching it again.
+    pub fn withdraw_with_coin_info(
+        &self, withdraw_request: WithdrawRequest, coin: &CoinInfo,
+    ) -> Result<WithdrawResponse> {
+        if coin.coin != withdraw_request.coin {
+            return Err(SdkError::Other(format!(
+                "Withdrawal of {} checked against {}",
+                withdraw_request.coin, coin.coin
+            )));
+        }
+        let network = match &withdraw_request.network {
+            Some(network) => coin.network_list.iter().find(|n| &n.network == network),
+            None => coin.network_list.iter().find(|n| n.is_default),
+        }
+        .ok_or_else(|| {
+            SdkError::Other(format!(
+                "Unknown network {} for {}",
+                withdraw_request.network.as_deref().unwrap_or("(default)"),
+                withdraw_request.coin
+            ))
+        })?;
+        network.validate_withdrawal(
+            &withdraw_request.address,
+            withdraw_request.address_tag.as_deref(),
+            withdraw_request.amount,
+        )?;
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("coin".into(), withdraw_request.coin);
+        parameters.insert("address".into(), encode_param(&withdraw_request.address));
+        parameters.insert("amount".into(), withdraw_request.amount.to_string());
+        if let Some(network) = withdraw_request.network {
+            parameters.insert("network".into(), network);
+        }
+        if let Some(address_tag) = withdraw_request.address_tag {
+            parameters.insert("addressTag".into(), encode_param(&address_tag));
+        }
+        if let Some(withdraw_order_id) = withdraw_request.withdraw_order_id {
+            parameters.insert("withdrawOrderId".into(), encode_param(&withdraw_order_id));
+        }
+        if let Some(transaction_fee_flag) = withdraw_request.transaction_fee_flag {
+            parameters.insert(
+                "transactionFeeFlag".into(),
+                transaction_fee_flag.to_string(),
+            );
+        }
+        if let Some(name) = withdraw_request.name {
+            parameters.insert("name".into(), encode_param(&name));
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+    }
+
+    /// Fetch the deposit history, at most 90 days per query.
+    pub fn deposit_history(
+        &self, history_request: DepositHistoryRequest,
+    ) -> Result<Vec<DepositRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = history_request.coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(status) = history_request.status {
+            parameters.insert("status".into(), status.to_string());
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(offset) = history_request.offset {
+            parameters.insert("offset".into(), offset.to_string());
+        }
+        if let Some(limit) = history_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        if let Some(tx_id) = history_request.tx_id {
+            parameters.insert("txId".into(), tx_id);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+    }
+
+    /// Fetch the withdrawal history, at most 90 days per query.
+    pub fn withdraw_history(
+        &self, history_request: WithdrawHistoryRequest,
+    ) -> Result<Vec<WithdrawRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = history_request.coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(withdraw_order_id) = history_request.withdraw_order_id {
+            parameters.insert("withdrawOrderId".into(), withdraw_order_id);
+        }
+        if let Some(status) = history_request.status {
+            parameters.insert("status".into(), status.to_string());
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(offset) = history_request.offset {
+            parameters.insert("offset".into(), offset.to_string());
+        }
+        if let Some(limit) = history_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+    }
+
+    /// Transfer between the wallets of the account.
+    ///
+    /// Transfers out of or into isolated margin need the isolated pair as
+    /// `from_symbol` or `to_symbol`, see `UniversalTransferType::requires_from_symbol`.
     pub fn transfer_funds<S>(
-        &self, asset: S, amount: f64, transfer_type: SpotFuturesTransferType,
+        &self, asset: S, amount: f64, transfer_type: UniversalTransferType,
+        from_symbol: Option<String>, to_symbol: Option<String>,
     ) -> Result<TransactionId>
     where
         S: Into<String>,
     {
+        if transfer_type.requires_from_symbol() && from_symbol.is_none() {
+            return Err(SdkError::Other(format!(
+                "{} transfers require a fromSymbol",
+                transfer_type
+            )));
+        }
+        if transfer_type.requires_to_symbol() && to_symbol.is_none() {
+            return Err(SdkError::Other(format!(
+                "{} transfers require a toSymbol",
+                transfer_type
+            )));
+        }
+
         let mut parameters = BTreeMap::new();
         parameters.insert("asset".into(), asset.into());
         parameters.insert("amount".into(), amount.to_string());
-        parameters.insert("type".into(), (transfer_type as u8).to_string());
+        parameters.insert("type".into(), transfer_type.to_string());
+        if let Some(from_symbol) = from_symbol {
+            parameters.insert("fromSymbol".into(), from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            parameters.insert("toSymbol".into(), to_symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+    }
+
+    /// Fetch one page of the universal transfer history of one transfer type.
+    ///
+    /// Only the last 6 months can be queried; pages are numbered from 1 and hold
+    /// at most 100 rows.
+    pub fn transfer_history(
+        &self, history_request: TransferHistoryRequest,
+    ) -> Result<PaginatedResponse<UniversalTransfer>> {
+        let (current, size) = (history_request.current, history_request.size);
+        let mut parameters = transfer_history_parameters(history_request);
+        if let Some(current) = current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+    }
+
+    /// Iterate over the rows of all pages of the universal transfer history.
+    ///
+    /// The `current` and `size` of the request are ignored.
+    pub fn transfer_history_pages(
+        &self, history_request: TransferHistoryRequest,
+    ) -> impl Iterator<Item = Result<UniversalTransfer>> + '_ {
+        let parameters = transfer_history_parameters(history_request);
+        self.paginate(Sapi::UniversalTransfer, parameters)
+    }
+
+    /// List the balances that can be converted to BNB.
+    pub fn dust_assets(&self, account_type: Option<DustAccountType>) -> Result<DustAssets> {
+        let mut parameters = BTreeMap::new();
+        if let Some(account_type) = account_type {
+            parameters.insert("accountType".into(), account_type.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::DustBtc), request)
+    }
+
+    /// Convert the dust of `assets` to BNB.
+    pub fn dust_transfer(
+        &self, assets: &[&str], account_type: Option<DustAccountType>,
+    ) -> Result<DustTransfer> {
+        if assets.is_empty() {
+            return Err(SdkError::Other("No dust asset to convert".into()));
+        }
+        let mut parameters = BTreeMap::new();
+        if let Some(account_type) = account_type {
+            parameters.insert("accountType".into(), account_type.to_string());
+        }
+        let mut request = build_signed_request(parameters, self.recv_window)?;
+        // `asset` is repeated once per asset, which a BTreeMap cannot hold
+        for asset in assets {
+            request.push_str(&format!("&asset={}", asset));
+        }
+        self.client.post_signed(API::Savings(Sapi::Dust), request)
+    }
+
+    /// Dust conversions between `start_time` and `end_time`, or of the last 100 days.
+    pub fn dust_log(&self, start_time: Option<u64>, end_time: Option<u64>) -> Result<DustLog> {
+        let mut parameters = BTreeMap::new();
+        if let Some(start_time) = start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
+            .get_signed(API::Savings(Sapi::DustLog), Some(request))
     }
 
+    /// Dividends and distributions, most recent first.
+    pub fn asset_dividend_history(
+        &self, history_request: DividendHistoryRequest,
+    ) -> Result<PaginatedResponse<AssetDividend>> {
+        let mut parameters = asset_parameters(history_request.asset);
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = history_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AssetDividend), Some(request))
+    }
+
+    /// Convertible dust worth less than `threshold` in `quote_asset`, valued with the
+    /// prices of `Market::get_all_prices`.
+    ///
+    /// Assets without an `<asset><quote_asset>` symbol are valued through their BTC value
+    /// and the `BTC<quote_asset>` price; assets that cannot be valued are left out. In BTC,
+    /// assets are valued with their BTC value, without fetching prices.
+    pub fn dust_under(
+        &self, market: &Market, quote_asset: &str, threshold: f64,
+        account_type: Option<DustAccountType>,
+    ) -> Result<Vec<DustAsset>> {
+        let dust = self.dust_assets(account_type)?;
+        if quote_asset == "BTC" {
+            return Ok(dust
+                .details
+                .into_iter()
+                .filter(|asset| asset.to_btc < threshold)
+                .collect());
+        }
+        let Prices::AllPrices(prices) = market.get_all_prices()?;
+        let prices: HashMap<String, f64> = prices
+            .into_iter()
+            .map(|price| (price.symbol, price.price))
+            .collect();
+        let btc_price = prices.get(&format!("BTC{}", quote_asset)).copied();
+
+        Ok(dust
+            .details
+            .into_iter()
+            .filter(|asset| {
+                let value = match prices.get(&format!("{}{}", asset.asset, quote_asset)) {
+                    Some(price) => Some(asset.amount_free * price),
+                    None => btc_price.map(|btc_price| asset.to_btc * btc_price),
+                };
+                value.is_some_and(|value| value < threshold)
+            })
+            .collect())
+    }
+
+    /// Convert to BNB all dust worth less than `threshold` in `quote_asset`, see
+    /// `dust_under`. Returns `None` if there is nothing to convert.
+    pub fn sweep_dust(
+        &self, market: &Market, quote_asset: &str, threshold: f64,
+        account_type: Option<DustAccountType>,
+    ) -> Result<Option<DustTransfer>> {
+        let dust = self.dust_under(market, quote_asset, threshold, account_type)?;
+        if dust.is_empty() {
+            return Ok(None);
+        }
+        let assets: Vec<&str> = dust.iter().map(|asset| asset.asset.as_str()).collect();
+        self.dust_transfer(&assets, account_type).map(Some)
+    }
+
+    /// Fetch the first page of flexible positions, see `simple_earn_flexible_positions`.
     pub fn simple_earn_flexible_list(&self) -> Result<PaginatedResponse<FlexibleProductInfo>> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
         self.client
             .get_signed(API::Savings(Sapi::SimpleEarnFlexible), Some(request))
     }
 
+    /// Fetch the first page of locked positions, see `simple_earn_locked_positions`.
     pub fn simple_earn_locked_list(&self) -> Result<PaginatedResponse<LockedProductInfo>> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
         self.client
             .get_signed(API::Savings(Sapi::SimpleEarnLocked), Some(request))
     }
+
+    /// Iterate over all flexible positions, optionally of one asset.
+    pub fn simple_earn_flexible_positions(
+        &self, asset: Option<String>,
+    ) -> impl Iterator<Item = Result<FlexibleProductInfo>> + '_ {
+        self.paginate(Sapi::SimpleEarnFlexible, asset_parameters(asset))
+    }
+
+    /// Iterate over all locked positions, optionally of one asset.
+    pub fn simple_earn_locked_positions(
+        &self, asset: Option<String>,
+    ) -> impl Iterator<Item = Result<LockedProductInfo>> + '_ {
+        self.paginate(Sapi::SimpleEarnLocked, asset_parameters(asset))
+    }
+
+    /// Iterate over the available flexible products, optionally of one asset.
+    pub fn simple_earn_flexible_products(
+        &self, asset: Option<String>,
+    ) -> impl Iterator<Item = Result<FlexibleProduct>> + '_ {
+        self.paginate(Sapi::SimpleEarnFlexibleList, asset_parameters(asset))
+    }
+
+    /// Iterate over the available locked products, optionally of one asset.
+    pub fn simple_earn_locked_products(
+        &self, asset: Option<String>,
+    ) -> impl Iterator<Item = Result<LockedProduct>> + '_ {
+        self.paginate(Sapi::SimpleEarnLockedList, asset_parameters(asset))
+    }
+
+    /// Subscribe to a flexible product, from the spot wallet unless `source_account` is set.
+    pub fn subscribe_flexible_product<S>(
+        &self, product_id: S, amount: f64, auto_subscribe: Option<bool>,
+        source_account: Option<EarnSourceAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        parameters.insert("amount".into(), amount.to_string());
+        if let Some(auto_subscribe) = auto_subscribe {
+            parameters.insert("autoSubscribe".into(), auto_subscribe.to_string());
+        }
+        if let Some(source_account) = source_account {
+            parameters.insert("sourceAccount".into(), source_account.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnFlexibleSubscribe), request)
+    }
+
+    /// Subscribe to a locked product, from the spot wallet unless `source_account` is set.
+    pub fn subscribe_locked_product<S>(
+        &self, project_id: S, amount: f64, auto_subscribe: Option<bool>,
+        source_account: Option<EarnSourceAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("projectId".into(), project_id.into());
+        parameters.insert("amount".into(), amount.to_string());
+        if let Some(auto_subscribe) = auto_subscribe {
+            parameters.insert("autoSubscribe".into(), auto_subscribe.to_string());
+        }
+        if let Some(source_account) = source_account {
+            parameters.insert("sourceAccount".into(), source_account.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnLockedSubscribe), request)
+    }
+
+    /// Redeem `amount` of a flexible product, or the whole position if `None`.
+    pub fn redeem_flexible_product<S>(
+        &self, product_id: S, amount: Option<f64>,
+    ) -> Result<EarnRedemption>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        match amount {
+            Some(amount) => parameters.insert("amount".into(), amount.to_string()),
+            None => parameters.insert("redeemAll".into(), "true".into()),
+        };
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnFlexibleRedeem), request)
+    }
+
+    /// Redeem a locked position before the end of its lock period.
+    pub fn redeem_locked_product(&self, position_id: u64) -> Result<EarnRedemption> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("positionId".into(), position_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SimpleEarnLockedRedeem), request)
+    }
+
+    pub fn flexible_subscription_history(
+        &self, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<FlexibleSubscriptionRecord>> + '_ {
+        self.paginate(
+            Sapi::SimpleEarnFlexibleSubscriptionRecord,
+            earn_history_parameters(history_request),
+        )
+    }
+
+    pub fn locked_subscription_history(
+        &self, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<LockedSubscriptionRecord>> + '_ {
+        self.paginate(
+            Sapi::SimpleEarnLockedSubscriptionRecord,
+            earn_history_parameters(history_request),
+        )
+    }
+
+    pub fn flexible_redemption_history(
+        &self, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<FlexibleRedemptionRecord>> + '_ {
+        self.paginate(
+            Sapi::SimpleEarnFlexibleRedemptionRecord,
+            earn_history_parameters(history_request),
+        )
+    }
+
+    pub fn locked_redemption_history(
+        &self, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<LockedRedemptionRecord>> + '_ {
+        self.paginate(
+            Sapi::SimpleEarnLockedRedemptionRecord,
+            earn_history_parameters(history_request),
+        )
+    }
+
+    pub fn flexible_rewards_history(
+        &self, reward_type: FlexibleRewardType, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<FlexibleRewardRecord>> + '_ {
+        let mut parameters = earn_history_parameters(history_request);
+        parameters.insert("type".into(), reward_type.to_string());
+        self.paginate(Sapi::SimpleEarnFlexibleRewardsRecord, parameters)
+    }
+
+    pub fn locked_rewards_history(
+        &self, history_request: EarnHistoryRequest,
+    ) -> impl Iterator<Item = Result<LockedRewardRecord>> + '_ {
+        self.paginate(
+            Sapi::SimpleEarnLockedRewardsRecord,
+            earn_history_parameters(history_request),
+        )
+    }
+
+    /// Amount that can still be subscribed to a flexible product.
+    pub fn flexible_personal_left_quota<S>(&self, product_id: S) -> Result<PersonalLeftQuota>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnFlexiblePersonalLeftQuota),
+            Some(request),
+        )
+    }
+
+    /// Amount that can still be subscribed to a locked product.
+    pub fn locked_personal_left_quota<S>(&self, project_id: S) -> Result<PersonalLeftQuota>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("projectId".into(), project_id.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SimpleEarnLockedPersonalLeftQuota),
+            Some(request),
+        )
+    }
+
+    fn paginate<'a, T: DeserializeOwned + 'a>(
+        &'a self, route: Sapi, parameters: BTreeMap<String, String>,
+    ) -> impl Iterator<Item = Result<T>> + 'a {
+        PaginatedResponse::pages(PAGE_SIZE, move |current, size| {
+            let mut parameters = parameters.clone();
+            parameters.insert("current".into(), current.to_string());
+            parameters.insert("size".into(), size.to_string());
+            let request = build_signed_request(parameters, self.recv_window)?;
+            self.client.get_signed(API::Savings(route), Some(request))
+        })
+    }
+}
+
+fn asset_parameters(asset: Option<String>) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    if let Some(asset) = asset {
+        parameters.insert("asset".into(), asset);
+    }
+    parameters
+}
+
+fn earn_history_parameters(history_request: EarnHistoryRequest) -> BTreeMap<String, String> {
+    let mut parameters = asset_parameters(history_request.asset);
+    if let Some(start_time) = history_request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = history_request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    parameters
+}
+
+// Filters of the transfer history, without the `current` and `size` of the page
+fn transfer_history_parameters(
+    history_request: TransferHistoryRequest,
+) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("type".into(), history_request.transfer_type.to_string());
+    if let Some(start_time) = history_request.start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = history_request.end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+    if let Some(from_symbol) = history_request.from_symbol {
+        parameters.insert("fromSymbol".into(), from_symbol);
+    }
+    if let Some(to_symbol) = history_request.to_symbol {
+        parameters.insert("toSymbol".into(), to_symbol);
+    }
+    parameters
 }
@@ -0,0 +1,405 @@
+use std::fmt::{self, Display};
+use crate::futures::account::ContractType;
+
+/// Interval of a kline, as in `btcusdt@kline_1m`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KlineInterval {
+    OneSecond,
+    OneMinute,
+    ThreeMinutes,
+    FiveMinutes,
+    FifteenMinutes,
+    ThirtyMinutes,
+    OneHour,
+    TwoHours,
+    FourHours,
+    SixHours,
+    EightHours,
+    TwelveHours,
+    OneDay,
+    ThreeDays,
+    OneWeek,
+    OneMonth,
+}
+
+impl Display for KlineInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OneSecond => write!(f, "1s"),
+            Self::OneMinute => write!(f, "1m"),
+            Self::ThreeMinutes => write!(f, "3m"),
+            Self::FiveMinutes => write!(f, "5m"),
+            Self::FifteenMinutes => write!(f, "15m"),
+            Self::ThirtyMinutes => write!(f, "30m"),
+            Self::OneHour => write!(f, "1h"),
+            Self::TwoHours => write!(f, "2h"),
+            Self::FourHours => write!(f, "4h"),
+            Self::SixHours => write!(f, "6h"),
+            Self::EightHours => write!(f, "8h"),
+            Self::TwelveHours => write!(f, "12h"),
+            Self::OneDay => write!(f, "1d"),
+            Self::ThreeDays => write!(f, "3d"),
+            Self::OneWeek => write!(f, "1w"),
+            Self::OneMonth => write!(f, "1M"),
+        }
+    }
+}
+
+impl From<KlineInterval> for String {
+    fn from(item: KlineInterval) -> Self {
+        item.to_string()
+    }
+}
+
+/// Number of price levels of a partial depth stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthLevels {
+    Five,
+    Ten,
+    Twenty,
+}
+
+impl Display for DepthLevels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Five => write!(f, "5"),
+            Self::Ten => write!(f, "10"),
+            Self::Twenty => write!(f, "20"),
+        }
+    }
+}
+
+/// Update speed of a depth stream; spot supports 100ms and 1000ms, futures 100ms, 250ms
+/// and 500ms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateSpeed {
+    Ms100,
+    Ms250,
+    Ms500,
+    Ms1000,
+}
+
+impl Display for UpdateSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ms100 => write!(f, "100ms"),
+            Self::Ms250 => write!(f, "250ms"),
+            Self::Ms500 => write!(f, "500ms"),
+            Self::Ms1000 => write!(f, "1000ms"),
+        }
+    }
+}
+
+/// Window of a rolling window ticker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerWindow {
+    OneHour,
+    FourHours,
+    OneDay,
+}
+
+impl Display for TickerWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OneHour => write!(f, "1h"),
+            Self::FourHours => write!(f, "4h"),
+            Self::OneDay => write!(f, "1d"),
+        }
+    }
+}
+
+/// Type of the events of a stream, displayed as their `e` field, e.g. `depthUpdate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventType {
+    Trade,
+    AggTrade,
+    Kline,
+    DepthUpdate,
+    MiniTicker,
+    Ticker,
+    WindowTicker(TickerWindow),
+    MarkPriceUpdate,
+    ForceOrder,
+    ContinuousKline,
+}
+
+impl Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Trade => write!(f, "trade"),
+            Self::AggTrade => write!(f, "aggTrade"),
+            Self::Kline => write!(f, "kline"),
+            Self::DepthUpdate => write!(f, "depthUpdate"),
+            Self::MiniTicker => write!(f, "24hrMiniTicker"),
+            Self::Ticker => write!(f, "24hrTicker"),
+            Self::WindowTicker(window) => write!(f, "{}Ticker", window),
+            Self::MarkPriceUpdate => write!(f, "markPriceUpdate"),
+            Self::ForceOrder => write!(f, "forceOrder"),
+            Self::ContinuousKline => write!(f, "continuous_kline"),
+        }
+    }
+}
+
+/// A websocket stream, displayed as its name, e.g. `btcusdt@depth@100ms`.
+///
+/// Symbols are lowercased. Streams can be passed to `connect_multiple_streams`, `subscribe`
+/// and `unsubscribe`, or turned into a name with `to_string()`. `MarkPrice`, `ForceOrder`
+/// and `ContinuousKline` only exist on futures.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stream {
+    Trade {
+        symbol: String,
+    },
+    AggTrade {
+        symbol: String,
+    },
+    Kline {
+        symbol: String,
+        interval: KlineInterval,
+    },
+    /// Top bids and asks, at the default speed if `speed` is `None`.
+    PartialDepth {
+        symbol: String,
+        levels: DepthLevels,
+        speed: Option<UpdateSpeed>,
+    },
+    /// Order book updates, at the default speed if `speed` is `None`.
+    DiffDepth {
+        symbol: String,
+        speed: Option<UpdateSpeed>,
+    },
+    BookTicker {
+        symbol: String,
+    },
+    AllBookTickers,
+    MiniTicker {
+        symbol: String,
+    },
+    AllMiniTickers,
+    /// 24 hour ticker.
+    Ticker {
+        symbol: String,
+    },
+    AllTickers,
+    WindowTicker {
+        symbol: String,
+        window: TickerWindow,
+    },
+    AllWindowTickers {
+        window: TickerWindow,
+    },
+    /// Every 3 seconds, or every second if `every_second` is set.
+    MarkPrice {
+        symbol: String,
+        every_second: bool,
+    },
+    AllMarkPrices {
+        every_second: bool,
+    },
+    ForceOrder {
+        symbol: String,
+    },
+    AllForceOrders,
+    ContinuousKline {
+        pair: String,
+        contract_type: ContractType,
+        interval: KlineInterval,
+    },
+    /// Account, balance and order events of the user data stream with this listen key.
+    UserData {
+        listen_key: String,
+    },
+}
+
+impl Stream {
+    pub fn trade<S: Into<String>>(symbol: S) -> Self {
+        Stream::Trade {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn agg_trade<S: Into<String>>(symbol: S) -> Self {
+        Stream::AggTrade {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn kline<S: Into<String>>(symbol: S, interval: KlineInterval) -> Self {
+        Stream::Kline {
+            symbol: symbol.into(),
+            interval,
+        }
+    }
+
+    pub fn partial_depth<S: Into<String>>(
+        symbol: S, levels: DepthLevels, speed: Option<UpdateSpeed>,
+    ) -> Self {
+        Stream::PartialDepth {
+            symbol: symbol.into(),
+            levels,
+            speed,
+        }
+    }
+
+    pub fn diff_depth<S: Into<String>>(symbol: S, speed: Option<UpdateSpeed>) -> Self {
+        Stream::DiffDepth {
+            symbol: symbol.into(),
+            speed,
+        }
+    }
+
+    pub fn book_ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::BookTicker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn mini_ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::MiniTicker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn ticker<S: Into<String>>(symbol: S) -> Self {
+        Stream::Ticker {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn window_ticker<S: Into<String>>(symbol: S, window: TickerWindow) -> Self {
+        Stream::WindowTicker {
+            symbol: symbol.into(),
+            window,
+        }
+    }
+
+    pub fn mark_price<S: Into<String>>(symbol: S, every_second: bool) -> Self {
+        Stream::MarkPrice {
+            symbol: symbol.into(),
+            every_second,
+        }
+    }
+
+    pub fn force_order<S: Into<String>>(symbol: S) -> Self {
+        Stream::ForceOrder {
+            symbol: symbol.into(),
+        }
+    }
+
+    pub fn continuous_kline<S: Into<String>>(
+        pair: S, contract_type: ContractType, interval: KlineInterval,
+    ) -> Self {
+        Stream::ContinuousKline {
+            pair: pair.into(),
+            contract_type,
+            interval,
+        }
+    }
+
+    pub fn user_data<S: Into<String>>(listen_key: S) -> Self {
+        Stream::UserData {
+            listen_key: listen_key.into(),
+        }
+    }
+
+    /// The type of the events of this stream.
+    ///
+    /// `None` for partial depths and book tickers, whose spot events have no type, and for
+    /// user data, whose events have several.
+    pub fn event_type(&self) -> Option<EventType> {
+        match self {
+            Stream::Trade { .. } => Some(EventType::Trade),
+            Stream::AggTrade { .. } => Some(EventType::AggTrade),
+            Stream::Kline { .. } => Some(EventType::Kline),
+            Stream::PartialDepth { .. }
+            | Stream::BookTicker { .. }
+            | Stream::AllBookTickers
+            | Stream::UserData { .. } => None,
+            Stream::DiffDepth { .. } => Some(EventType::DepthUpdate),
+            Stream::MiniTicker { .. } | Stream::AllMiniTickers => Some(EventType::MiniTicker),
+            Stream::Ticker { .. } | Stream::AllTickers => Some(EventType::Ticker),
+            Stream::WindowTicker { window, .. } | Stream::AllWindowTickers { window } => {
+                Some(EventType::WindowTicker(*window))
+            }
+            Stream::MarkPrice { .. } | Stream::AllMarkPrices { .. } => {
+                Some(EventType::MarkPriceUpdate)
+            }
+            Stream::ForceOrder { .. } | Stream::AllForceOrders => Some(EventType::ForceOrder),
+            Stream::ContinuousKline { .. } => Some(EventType::ContinuousKline),
+        }
+    }
+}
+
+fn speed_suffix(speed: &Option<UpdateSpeed>) -> String {
+    speed.map(|speed| format!("@{}", speed)).unwrap_or_default()
+}
+
+fn every_second_suffix(every_second: bool) -> &'static str {
+    if every_second {
+        "@1s"
+    } else {
+        ""
+    }
+}
+
+impl Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stream::Trade { symbol } => write!(f, "{}@trade", symbol.to_lowercase()),
+            Stream::AggTrade { symbol } => write!(f, "{}@aggTrade", symbol.to_lowercase()),
+            Stream::Kline { symbol, interval } => {
+                write!(f, "{}@kline_{}", symbol.to_lowercase(), interval)
+            }
+            Stream::PartialDepth {
+                symbol,
+                levels,
+                speed,
+            } => write!(
+                f,
+                "{}@depth{}{}",
+                symbol.to_lowercase(),
+                levels,
+                speed_suffix(speed)
+            ),
+            Stream::DiffDepth { symbol, speed } => {
+                write!(f, "{}@depth{}", symbol.to_lowercase(), speed_suffix(speed))
+            }
+            Stream::BookTicker { symbol } => write!(f, "{}@bookTicker", symbol.to_lowercase()),
+            Stream::AllBookTickers => write!(f, "!bookTicker"),
+            Stream::MiniTicker { symbol } => write!(f, "{}@miniTicker", symbol.to_lowercase()),
+            Stream::AllMiniTickers => write!(f, "!miniTicker@arr"),
+            Stream::Ticker { symbol } => write!(f, "{}@ticker", symbol.to_lowercase()),
+            Stream::AllTickers => write!(f, "!ticker@arr"),
+            Stream::WindowTicker { symbol, window } => {
+                write!(f, "{}@ticker_{}", symbol.to_lowercase(), window)
+            }
+            Stream::AllWindowTickers { window } => write!(f, "!ticker_{}@arr", window),
+            Stream::MarkPrice {
+                symbol,
+                every_second,
+            } => write!(
+                f,
+                "{}@markPrice{}",
+                symbol.to_lowercase(),
+                every_second_suffix(*every_second)
+            ),
+            Stream::AllMarkPrices { every_second } => {
+                write!(f, "!markPrice@arr{}", every_second_suffix(*every_second))
+            }
+            Stream::ForceOrder { symbol } => write!(f, "{}@forceOrder", symbol.to_lowercase()),
+            Stream::AllForceOrders => write!(f, "!forceOrder@arr"),
+            Stream::ContinuousKline {
+                pair,
+                contract_type,
+                interval,
+            } => write!(
+                f,
+                "{}_{}@continuousKline_{}",
+                pair.to_lowercase(),
+                String::from(*contract_type).to_lowercase(),
+                interval
+            ),
+            Stream::UserData { listen_key } => write!(f, "{}", listen_key),
+        }
+    }
+}
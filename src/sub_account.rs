@@ -0,0 +1,424 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Display;
+use crate::util::{build_signed_request, encode_param};
+use crate::model::{
+    ApiKeyIpRestriction, ApiKeyIpRestrictionUpdate, CreatedSubAccount, PaginatedResponse,
+    SubAccountCoinmFuturesSummary, SubAccountFuturesEnabled, SubAccountFuturesSummaryResponse,
+    SubAccountInfo, SubAccountList, SubAccountMarginEnabled, SubAccountMarginSummary,
+    SubAccountSpotAsset, SubAccountSpotSummary, SubAccountTransfer, SubAccountTransferHistory,
+    SubAccountTransferId, SubAccountUsdmFuturesSummary,
+};
+use crate::client::Client;
+use crate::errors::{Result, SdkError};
+use crate::api::API;
+use crate::api::Sapi;
+
+const LIST_PAGE_SIZE: u32 = 200;
+const SPOT_SUMMARY_PAGE_SIZE: u32 = 20;
+const TRANSFER_PAGE_SIZE: u32 = 500;
+
+/// Sub-account management, called with the API key of the master account.
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Wallet of a master or sub-account, for universal transfers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubAccountWallet {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+impl Display for SubAccountWallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::UsdtFuture => write!(f, "USDT_FUTURE"),
+            Self::CoinFuture => write!(f, "COIN_FUTURE"),
+            Self::Margin => write!(f, "MARGIN"),
+            Self::IsolatedMargin => write!(f, "ISOLATED_MARGIN"),
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct SubAccountListRequest {
+    pub email: Option<String>,
+    pub is_freeze: Option<bool>,
+    /// Page number, starting at 1.
+    pub page: Option<u32>,
+    /// Rows per page, at most 200.
+    pub limit: Option<u32>,
+}
+
+/// Universal transfer between the wallets of the master account and its sub-accounts.
+///
+/// A `None` email is the master account; at least one of `from_email` and `to_email`
+/// must be set.
+pub struct SubAccountTransferRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountWallet,
+    pub to_account_type: SubAccountWallet,
+    pub client_tran_id: Option<String>,
+    /// Isolated margin pair, required when either wallet is `IsolatedMargin`.
+    pub symbol: Option<String>,
+    pub asset: String,
+    pub amount: f64,
+}
+
+impl SubAccountTransferRequest {
+    pub fn new<S: Into<String>>(
+        asset: S, amount: f64, from_account_type: SubAccountWallet,
+        to_account_type: SubAccountWallet,
+    ) -> Self {
+        SubAccountTransferRequest {
+            from_email: None,
+            to_email: None,
+            from_account_type,
+            to_account_type,
+            client_tran_id: None,
+            symbol: None,
+            asset: asset.into(),
+            amount,
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct SubAccountTransferHistoryRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub client_tran_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1.
+    pub page: Option<u32>,
+    /// Rows per page, at most 500.
+    pub limit: Option<u32>,
+}
+
+impl SubAccount {
+    /// List the sub-accounts of the master account, one page at a time.
+    pub fn list(&self, list_request: SubAccountListRequest) -> Result<Vec<SubAccountInfo>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(email) = list_request.email {
+            parameters.insert("email".into(), encode_param(&email));
+        }
+        if let Some(is_freeze) = list_request.is_freeze {
+            parameters.insert("isFreeze".into(), is_freeze.to_string());
+        }
+        if let Some(page) = list_request.page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(limit) = list_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<SubAccountList>(API::Savings(Sapi::SubAccountList), Some(request))
+            .map(|list| list.sub_accounts)
+    }
+
+    /// Iterate over all sub-accounts. The `page` and `limit` of the request are ignored.
+    pub fn list_pages(
+        &self, list_request: SubAccountListRequest,
+    ) -> impl Iterator<Item = Result<SubAccountInfo>> + '_ {
+        let mut page = 1;
+        let mut buffer = VecDeque::new();
+        let mut done = false;
+        std::iter::from_fn(move || {
+            if buffer.is_empty() && !done {
+                let sub_accounts = self.list(SubAccountListRequest {
+                    page: Some(page),
+                    limit: Some(LIST_PAGE_SIZE),
+                    ..list_request.clone()
+                });
+                match sub_accounts {
+                    Ok(sub_accounts) => {
+                        // The endpoint returns no count: a short page is the last one
+                        page += 1;
+                        done = sub_accounts.len() < LIST_PAGE_SIZE as usize;
+                        buffer.extend(sub_accounts);
+                    }
+                    Err(e) => {
+                        done = true;
+                        return Some(Err(e));
+                    }
+                }
+            }
+            buffer.pop_front().map(Ok)
+        })
+    }
+
+    /// Create a virtual sub-account. Binance derives its email from `sub_account_string`.
+    pub fn create<S>(&self, sub_account_string: S) -> Result<CreatedSubAccount>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("subAccountString".into(), sub_account_string.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountCreate), request)
+    }
+
+    /// Spot assets of the sub-accounts in BTC, one page at a time, or of ONE
+    /// sub-account if `email` is set.
+    pub fn spot_summary(
+        &self, email: Option<String>, page: Option<u32>, size: Option<u32>,
+    ) -> Result<SubAccountSpotSummary> {
+        let mut parameters = BTreeMap::new();
+        if let Some(email) = email {
+            parameters.insert("email".into(), encode_param(&email));
+        }
+        if let Some(page) = page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountSpotSummary), Some(request))
+    }
+
+    /// Iterate over the spot assets of all sub-accounts.
+    pub fn spot_summary_pages(&self) -> impl Iterator<Item = Result<SubAccountSpotAsset>> + '_ {
+        PaginatedResponse::pages(SPOT_SUMMARY_PAGE_SIZE, move |page, size| {
+            let summary = self.spot_summary(None, Some(page), Some(size))?;
+            Ok(PaginatedResponse {
+                data: summary.sub_accounts,
+                total: summary.total_count,
+            })
+        })
+    }
+
+    /// USDⓈ-M futures summary of the sub-accounts, one page of sub-accounts at a time.
+    pub fn usdm_futures_summary(
+        &self, page: Option<u32>, limit: Option<u32>,
+    ) -> Result<SubAccountUsdmFuturesSummary> {
+        self.futures_summary(1, page, limit)?
+            .future_account_summary_resp
+            .ok_or_else(|| SdkError::Other("Missing USDⓈ-M futures account summary".into()))
+    }
+
+    /// COIN-M futures summary of the sub-accounts, one page of sub-accounts at a time.
+    pub fn coinm_futures_summary(
+        &self, page: Option<u32>, limit: Option<u32>,
+    ) -> Result<SubAccountCoinmFuturesSummary> {
+        self.futures_summary(2, page, limit)?
+            .delivery_account_summary_resp
+            .ok_or_else(|| SdkError::Other("Missing COIN-M futures account summary".into()))
+    }
+
+    /// Cross margin summary of all sub-accounts.
+    pub fn margin_summary(&self) -> Result<SubAccountMarginSummary> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountMarginSummary), Some(request))
+    }
+
+    /// Transfer between the wallets of the master account and its sub-accounts.
+    pub fn universal_transfer(
+        &self, transfer_request: SubAccountTransferRequest,
+    ) -> Result<SubAccountTransferId> {
+        if transfer_request.from_email.is_none() && transfer_request.to_email.is_none() {
+            return Err(SdkError::Other(
+                "A sub-account universal transfer needs a from_email or a to_email".into(),
+            ));
+        }
+        let isolated = transfer_request.from_account_type == SubAccountWallet::IsolatedMargin
+            || transfer_request.to_account_type == SubAccountWallet::IsolatedMargin;
+        if isolated && transfer_request.symbol.is_none() {
+            return Err(SdkError::Other(
+                "An isolated margin transfer needs the symbol of the pair".into(),
+            ));
+        }
+
+        let mut parameters = BTreeMap::new();
+        if let Some(from_email) = transfer_request.from_email {
+            parameters.insert("fromEmail".into(), encode_param(&from_email));
+        }
+        if let Some(to_email) = transfer_request.to_email {
+            parameters.insert("toEmail".into(), encode_param(&to_email));
+        }
+        parameters.insert(
+            "fromAccountType".into(),
+            transfer_request.from_account_type.to_string(),
+        );
+        parameters.insert(
+            "toAccountType".into(),
+            transfer_request.to_account_type.to_string(),
+        );
+        if let Some(client_tran_id) = transfer_request.client_tran_id {
+            parameters.insert("clientTranId".into(), client_tran_id);
+        }
+        if let Some(symbol) = transfer_request.symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        parameters.insert("asset".into(), transfer_request.asset);
+        parameters.insert("amount".into(), transfer_request.amount.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountUniversalTransfer), request)
+    }
+
+    /// Fetch one page of the universal transfer history.
+    pub fn universal_transfer_history(
+        &self, history_request: SubAccountTransferHistoryRequest,
+    ) -> Result<PaginatedResponse<SubAccountTransfer>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(from_email) = history_request.from_email {
+            parameters.insert("fromEmail".into(), encode_param(&from_email));
+        }
+        if let Some(to_email) = history_request.to_email {
+            parameters.insert("toEmail".into(), encode_param(&to_email));
+        }
+        if let Some(client_tran_id) = history_request.client_tran_id {
+            parameters.insert("clientTranId".into(), client_tran_id);
+        }
+        if let Some(start_time) = history_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = history_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(page) = history_request.page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(limit) = history_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<SubAccountTransferHistory>(
+                API::Savings(Sapi::SubAccountUniversalTransfer),
+                Some(request),
+            )
+            .map(|history| PaginatedResponse {
+                data: history.result,
+                total: history.total_count,
+            })
+    }
+
+    /// Iterate over the rows of all pages of the universal transfer history.
+    ///
+    /// The `page` and `limit` of the request are ignored.
+    pub fn universal_transfer_history_pages(
+        &self, history_request: SubAccountTransferHistoryRequest,
+    ) -> impl Iterator<Item = Result<SubAccountTransfer>> + '_ {
+        PaginatedResponse::pages(TRANSFER_PAGE_SIZE, move |page, limit| {
+            self.universal_transfer_history(SubAccountTransferHistoryRequest {
+                page: Some(page),
+                limit: Some(limit),
+                ..history_request.clone()
+            })
+        })
+    }
+
+    /// Enable USDⓈ-M futures on a sub-account.
+    pub fn enable_futures<S>(&self, email: S) -> Result<SubAccountFuturesEnabled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountFuturesEnable), request)
+    }
+
+    /// Enable margin on a sub-account.
+    pub fn enable_margin<S>(&self, email: S) -> Result<SubAccountMarginEnabled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("email".into(), encode_param(&email.into()));
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountMarginEnable), request)
+    }
+
+    /// IP restriction of a sub-account API key.
+    pub fn ip_restriction<E, K>(&self, email: E, api_key: K) -> Result<ApiKeyIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+    {
+        let parameters = api_key_parameters(email.into(), api_key.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountIpRestriction), Some(request))
+    }
+
+    /// Restrict a sub-account API key to `ip_addresses`, which are added to the
+    /// current list, or lift the restriction if `restrict` is false.
+    pub fn update_ip_restriction<E, K>(
+        &self, email: E, api_key: K, restrict: bool, ip_addresses: &[&str],
+    ) -> Result<ApiKeyIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+    {
+        let mut parameters = api_key_parameters(email.into(), api_key.into());
+        parameters.insert("status".into(), if restrict { "2" } else { "1" }.into());
+        if !ip_addresses.is_empty() {
+            parameters.insert("ipAddress".into(), ip_addresses.join(","));
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<ApiKeyIpRestrictionUpdate>(
+                API::Savings(Sapi::SubAccountIpRestrictionUpdate),
+                request,
+            )
+            .map(ApiKeyIpRestriction::from)
+    }
+
+    /// Remove `ip_addresses` from the IP list of a sub-account API key.
+    pub fn delete_ip_restriction<E, K>(
+        &self, email: E, api_key: K, ip_addresses: &[&str],
+    ) -> Result<ApiKeyIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+    {
+        if ip_addresses.is_empty() {
+            return Err(SdkError::Other("No IP address to remove".into()));
+        }
+        let mut parameters = api_key_parameters(email.into(), api_key.into());
+        parameters.insert("ipAddress".into(), ip_addresses.join(","));
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Savings(Sapi::SubAccountIpList), Some(request))
+    }
+
+    fn futures_summary(
+        &self, futures_type: u8, page: Option<u32>, limit: Option<u32>,
+    ) -> Result<SubAccountFuturesSummaryResponse> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("futuresType".into(), futures_type.to_string());
+        if let Some(page) = page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::SubAccountFuturesSummary), Some(request))
+    }
+}
+
+fn api_key_parameters(email: String, api_key: String) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("email".into(), encode_param(&email));
+    parameters.insert("subAccountApiKey".into(), api_key);
+    parameters
+}
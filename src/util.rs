@@ -13,6 +13,11 @@ pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     request
 }
 
+// Percent-encoded, so that free text can be sent (and signed) in the query string
+pub(crate) fn encode_param(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
 pub fn build_signed_request(
     parameters: BTreeMap<String, String>, recv_window: u64,
 ) -> Result<String> {
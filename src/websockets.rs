@@ -1,5 +1,5 @@
-use crate::errors::Result;
-use crate::config::Config;
+use crate::errors::{BinanceContentError, Result, SdkError};
+use crate::config::{combined_stream_url, raw_stream_url, Config};
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent, DayTickerEvent,
     WindowTickerEvent, DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent,
@@ -8,34 +8,418 @@ use url::Url;
 use serde::{Deserialize, Serialize};
 
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::io::ErrorKind;
 use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, Instant};
 use tungstenite::{connect, Message};
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
 
-#[allow(clippy::all)]
-enum WebsocketAPI {
-    Default,
-    MultiStream,
-    Custom(String),
+// How often a supervised event loop wakes up to check the running flag and the connection age
+const SUPERVISED_READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Reconnect settings of `event_loop_with_reconnect`.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Wait after the first failed attempt, doubled after each further one.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Failed attempts in a row before giving up, unlimited if `None`.
+    pub max_attempts: Option<u32>,
+    /// Reconnect once the connection is this old, as Binance closes connections after 24 hours.
+    pub max_connection_age: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: None,
+            max_connection_age: Duration::from_secs(23 * 60 * 60),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    pub(crate) fn backoff(&self, failed_attempts: u32) -> Duration {
+        let factor = 2u32.saturating_pow(failed_attempts.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+// Read timeout of the socket, `None` if reads block
+fn read_timeout(socket: &WebSocket<MaybeTlsStream<TcpStream>>) -> Result<Option<Duration>> {
+    let timeout = match socket.get_ref() {
+        MaybeTlsStream::Plain(stream) => stream.read_timeout()?,
+        MaybeTlsStream::NativeTls(stream) => stream.get_ref().read_timeout()?,
+        _ => None,
+    };
+    Ok(timeout)
+}
+
+fn set_read_timeout(
+    socket: &WebSocket<MaybeTlsStream<TcpStream>>, timeout: Option<Duration>,
+) -> Result<()> {
+    match socket.get_ref() {
+        MaybeTlsStream::Plain(stream) => stream.set_read_timeout(timeout)?,
+        MaybeTlsStream::NativeTls(stream) => stream.get_ref().set_read_timeout(timeout)?,
+        _ => (),
+    }
+    Ok(())
+}
+
+fn is_read_timeout(error: &tungstenite::Error) -> bool {
+    match error {
+        tungstenite::Error::Io(error) => {
+            matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut)
+        }
+        _ => false,
+    }
+}
+
+// Binance drops connections sending more than 5 messages per second
+const CONTROL_MESSAGES_PER_SECOND: usize = 5;
+// Default wait for the response to a control request
+const CONTROL_RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+struct ControlRequest<'a> {
+    method: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    params: Vec<serde_json::Value>,
+    id: u64,
 }
 
-impl WebsocketAPI {
-    fn params(self, subscription: &str) -> String {
-        match self {
-            WebsocketAPI::Default => format!("wss://stream.binance.com/ws/{}", subscription),
-            WebsocketAPI::MultiStream => {
-                format!("wss://stream.binance.com/stream?streams={}", subscription)
+#[derive(Deserialize)]
+struct ControlResponse {
+    id: Option<u64>,
+    #[serde(default)]
+    result: serde_json::Value,
+    error: Option<BinanceContentError>,
+    code: Option<i32>,
+    msg: Option<String>,
+}
+
+// Request ids and send times of the SUBSCRIBE, UNSUBSCRIBE, LIST_SUBSCRIPTIONS and
+// SET_PROPERTY messages of one connection
+#[derive(Default)]
+struct ControlChannel {
+    next_id: u64,
+    sent: VecDeque<Instant>,
+}
+
+impl ControlChannel {
+    // Builds the next request, waiting first if it would exceed the message rate limit
+    fn request(&mut self, method: &str, params: Vec<serde_json::Value>) -> Result<(u64, String)> {
+        if self.sent.len() == CONTROL_MESSAGES_PER_SECOND {
+            if let Some(oldest) = self.sent.pop_front() {
+                let wait = Duration::from_secs(1).saturating_sub(oldest.elapsed());
+                thread::sleep(wait);
             }
-            WebsocketAPI::Custom(url) => format!("{}/{}", url, subscription),
         }
+        self.sent.push_back(Instant::now());
+        self.next_id += 1;
+        let request = ControlRequest {
+            method,
+            params,
+            id: self.next_id,
+        };
+        Ok((self.next_id, serde_json::to_string(&request)?))
+    }
+}
+
+// The result of the control request `id` if `msg` is its response
+fn control_response(msg: &str, id: u64) -> Option<Result<serde_json::Value>> {
+    let response = serde_json::from_str::<ControlResponse>(msg).ok()?;
+    if response.id != Some(id) {
+        return None;
+    }
+    if let Some(error) = response.error {
+        return Some(Err(error.into()));
+    }
+    if let (Some(code), Some(msg)) = (response.code, response.msg) {
+        return Some(Err(BinanceContentError { code, msg }.into()));
+    }
+    Some(Ok(response.result))
+}
+
+// Url and streams of the last connection, to reconnect to
+#[derive(Default)]
+pub(crate) struct ConnectionState {
+    url: Option<String>,
+    url_streams: Vec<String>,
+    pub(crate) streams: Vec<String>,
+    combined: Option<bool>,
+    connected_at: Option<Instant>,
+    control: ControlChannel,
+    pub(crate) control_response_timeout: Option<Duration>,
+}
+
+// Changes of the connection reported to the handler by a supervised event loop
+pub(crate) enum Lifecycle {
+    Connected,
+    Disconnected(String),
+    Reconnected { attempts: u32 },
+}
+
+// Connecting, subscription control and supervised reconnects, shared by the spot and
+// futures websockets
+pub(crate) trait StreamConnection {
+    fn socket(&mut self) -> &mut Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>;
+    fn state(&mut self) -> &mut ConnectionState;
+    // Passes the event of a stream message to the handler
+    fn handle_text(&mut self, msg: &str) -> Result<()>;
+    fn handle_lifecycle(&mut self, event: Lifecycle) -> Result<()>;
+
+    fn websocket(&mut self) -> Result<&mut WebSocket<MaybeTlsStream<TcpStream>>> {
+        match self.socket() {
+            Some((socket, _)) => Ok(socket),
+            None => Err(SdkError::Other("Not connected".to_string())),
+        }
+    }
+
+    // Connects to `wss`, whose url names the `streams`
+    fn connect_streams(&mut self, wss: &str, streams: Vec<String>) -> Result<()> {
+        self.connect_wss(wss)?;
+        let state = self.state();
+        state.url_streams = streams.clone();
+        state.streams = streams;
+        state.combined = None;
+        Ok(())
+    }
+
+    fn connect_wss(&mut self, wss: &str) -> Result<()> {
+        let url = Url::parse(wss)?;
+        match connect(url) {
+            Ok(answer) => {
+                *self.socket() = Some(answer);
+                let state = self.state();
+                state.url = Some(wss.to_string());
+                state.connected_at = Some(Instant::now());
+                Ok(())
+            }
+            // Err(e) => bail!(format!("Error during handshake {}", e)),
+            Err(e) => Err(format!("Error during handshake {}", e).into()),
+        }
+    }
+
+    fn subscribe_streams(&mut self, streams: Vec<String>) -> Result<()> {
+        self.send_control(
+            "SUBSCRIBE",
+            streams.iter().map(|s| s.as_str().into()).collect(),
+        )?;
+        let state = self.state();
+        for stream in streams {
+            if !state.streams.contains(&stream) {
+                state.streams.push(stream);
+            }
+        }
+        Ok(())
+    }
+
+    fn unsubscribe_streams(&mut self, streams: Vec<String>) -> Result<()> {
+        self.send_control(
+            "UNSUBSCRIBE",
+            streams.iter().map(|s| s.as_str().into()).collect(),
+        )?;
+        self.state()
+            .streams
+            .retain(|stream| !streams.contains(stream));
+        Ok(())
+    }
+
+    fn request_subscriptions(&mut self) -> Result<Vec<String>> {
+        let result = self.send_control("LIST_SUBSCRIPTIONS", Vec::new())?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    fn set_combined_property(&mut self, combined: bool) -> Result<()> {
+        self.send_control("SET_PROPERTY", vec!["combined".into(), combined.into()])?;
+        self.state().combined = Some(combined);
+        Ok(())
+    }
+
+    // Sends a control request and reads until its response, handling the events read meanwhile
+    fn send_control(
+        &mut self, method: &str, params: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let (id, request) = self.state().control.request(method, params)?;
+        let socket = self.websocket()?;
+        socket.send(Message::Text(request))?;
+
+        // Reads block outside the supervised event loop, which would outlast the deadline
+        let timeout = read_timeout(socket)?;
+        set_read_timeout(socket, Some(SUPERVISED_READ_TIMEOUT))?;
+        let response = self.read_control_response(method, id);
+        if let Some((socket, _)) = self.socket() {
+            set_read_timeout(socket, timeout)?;
+        }
+        response
+    }
+
+    fn read_control_response(&mut self, method: &str, id: u64) -> Result<serde_json::Value> {
+        let timeout = self
+            .state()
+            .control_response_timeout
+            .unwrap_or(CONTROL_RESPONSE_TIMEOUT);
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            let message = match self.websocket()?.read() {
+                Ok(message) => message,
+                Err(e) if is_read_timeout(&e) => continue,
+                Err(e) => return Err(e.into()),
+            };
+            match message {
+                Message::Text(msg) => {
+                    if let Some(response) = control_response(&msg, id) {
+                        return response;
+                    }
+                    self.handle_text(&msg)?;
+                }
+                Message::Ping(payload) => self.websocket()?.send(Message::Pong(payload))?,
+                Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
+                Message::Close(e) => {
+                    return Err(format!("Disconnected from server: {:?}", e).into());
+                }
+            }
+        }
+        Err(format!("No response to {} request {}", method, id).into())
+    }
+
+    // Restores the streams and properties changed since connecting, after a reconnect
+    fn resubscribe(&mut self) -> Result<()> {
+        let state = self.state();
+        let added: Vec<String> = (state.streams.iter())
+            .filter(|stream| !state.url_streams.contains(stream))
+            .cloned()
+            .collect();
+        let removed: Vec<String> = (state.url_streams.iter())
+            .filter(|stream| !state.streams.contains(stream))
+            .cloned()
+            .collect();
+        let combined = state.combined;
+        if !added.is_empty() {
+            self.subscribe_streams(added)?;
+        }
+        if !removed.is_empty() {
+            self.unsubscribe_streams(removed)?;
+        }
+        if let Some(combined) = combined {
+            self.set_combined_property(combined)?;
+        }
+        Ok(())
+    }
+
+    fn supervise(&mut self, running: &AtomicBool, policy: &ReconnectPolicy) -> Result<()> {
+        let Some((socket, _)) = self.socket().as_ref() else {
+            return Err(SdkError::Other(
+                "Connect before starting the event loop".to_string(),
+            ));
+        };
+        set_read_timeout(socket, Some(SUPERVISED_READ_TIMEOUT))?;
+        self.handle_lifecycle(Lifecycle::Connected)?;
+
+        while running.load(Ordering::Relaxed) {
+            if let Some(reason) = self.read_supervised(policy)? {
+                self.handle_lifecycle(Lifecycle::Disconnected(reason))?;
+                match self.reconnect(running, policy)? {
+                    Some(attempts) => self.handle_lifecycle(Lifecycle::Reconnected { attempts })?,
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Handles the next message, returning why the connection has to be replaced, if it does
+    fn read_supervised(&mut self, policy: &ReconnectPolicy) -> Result<Option<String>> {
+        if self.socket().is_none() {
+            return Ok(Some("Not connected".to_string()));
+        }
+        if self
+            .state()
+            .connected_at
+            .is_some_and(|connected_at| connected_at.elapsed() >= policy.max_connection_age)
+        {
+            return Ok(Some("Connection reached its maximum age".to_string()));
+        }
+        let message = match self.websocket()?.read() {
+            Ok(message) => message,
+            Err(e) if is_read_timeout(&e) => return Ok(None),
+            Err(e) => return Ok(Some(format!("Read error: {}", e))),
+        };
+        match message {
+            Message::Text(msg) => {
+                if let Err(e) = self.handle_text(&msg) {
+                    return Err(format!("Error on handling stream message: {}", e).into());
+                }
+            }
+            Message::Ping(payload) => {
+                if let Err(e) = self.websocket()?.send(Message::Pong(payload)) {
+                    return Ok(Some(format!("Write error: {}", e)));
+                }
+            }
+            Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
+            Message::Close(e) => return Ok(Some(format!("Disconnected from server: {:?}", e))),
+        }
+        Ok(None)
+    }
+
+    // Connects again to the last url, returning the number of attempts, or `None` if stopped
+    fn reconnect(&mut self, running: &AtomicBool, policy: &ReconnectPolicy) -> Result<Option<u32>> {
+        let url = (self.state().url.clone())
+            .ok_or_else(|| SdkError::Other("No connection to restore".to_string()))?;
+        if let Some((mut socket, _)) = self.socket().take() {
+            let _ = socket.close(None);
+        }
+        let mut attempts = 0;
+        while running.load(Ordering::Relaxed) {
+            attempts += 1;
+            // A connection whose streams cannot be restored counts as a failed attempt
+            let restored = self.connect_wss(&url).and_then(|()| {
+                if let Some((socket, _)) = self.socket() {
+                    set_read_timeout(socket, Some(SUPERVISED_READ_TIMEOUT))?;
+                }
+                self.resubscribe()
+            });
+            match restored {
+                Ok(()) => return Ok(Some(attempts)),
+                Err(e) => {
+                    if let Some((mut socket, _)) = self.socket().take() {
+                        let _ = socket.close(None);
+                    }
+                    if policy.max_attempts.is_some_and(|max| attempts >= max) {
+                        return Err(SdkError::Other(format!(
+                            "Gave up reconnecting after {} attempts: {}",
+                            attempts, e
+                        )));
+                    }
+                    thread::sleep(policy.backoff(attempts));
+                }
+            }
+        }
+        Ok(None)
     }
 }
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum WebsocketEvent {
+    /// The supervised event loop started on a live connection.
+    Connected,
+    /// The connection dropped or reached its maximum age, with the reason.
+    Disconnected(String),
+    /// Connected again, after `attempts` tries.
+    Reconnected {
+        attempts: u32,
+    },
     AccountUpdate(AccountUpdateEvent),
     BalanceUpdate(BalanceUpdateEvent),
     OrderTrade(OrderTradeEvent),
@@ -54,6 +438,7 @@ pub enum WebsocketEvent {
 pub struct WebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(WebsocketEvent) -> Result<()> + 'a>,
+    connection: ConnectionState,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -82,31 +467,71 @@ impl<'a> WebSockets<'a> {
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            connection: ConnectionState::default(),
         }
     }
 
     pub fn connect(&mut self, subscription: &str) -> Result<()> {
-        self.connect_wss(&WebsocketAPI::Default.params(subscription))
+        self.connect_with_config(subscription, &Config::default())
     }
 
+    // Connects to the stream on `config.ws_endpoint`
     pub fn connect_with_config(&mut self, subscription: &str, config: &Config) -> Result<()> {
-        self.connect_wss(&WebsocketAPI::Custom(config.ws_endpoint.clone()).params(subscription))
+        self.connect_streams(
+            &raw_stream_url(&config.ws_endpoint, subscription)?,
+            vec![subscription.to_string()],
+        )
     }
 
-    pub fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
-        self.connect_wss(&WebsocketAPI::MultiStream.params(&endpoints.join("/")))
+    // Connects to several streams, given as names or as `streams::Stream`
+    pub fn connect_multiple_streams<S: Display>(&mut self, endpoints: &[S]) -> Result<()> {
+        self.connect_multiple_streams_with_config(endpoints, &Config::default())
     }
 
-    fn connect_wss(&mut self, wss: &str) -> Result<()> {
-        let url = Url::parse(wss)?;
-        match connect(url) {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            // Err(e) => bail!(format!("Error during handshake {}", e)),
-            Err(e) => Err(format!("Error during handshake {}", e).into()),
-        }
+    // Connects to several streams on the combined stream endpoint next to `config.ws_endpoint`
+    pub fn connect_multiple_streams_with_config<S: Display>(
+        &mut self, endpoints: &[S], config: &Config,
+    ) -> Result<()> {
+        let endpoints: Vec<String> = endpoints.iter().map(|e| e.to_string()).collect();
+        self.connect_streams(
+            &combined_stream_url(&config.ws_endpoint, &endpoints)?,
+            endpoints,
+        )
+    }
+
+    /// Wait up to `timeout` for Binance to answer subscription and property requests, 10 seconds
+    /// by default.
+    pub fn set_control_response_timeout(mut self, timeout: Duration) -> Self {
+        self.connection.control_response_timeout = Some(timeout);
+        self
+    }
+
+    // Streams of the current connection, including the ones subscribed after connecting
+    pub fn streams(&self) -> &[String] {
+        &self.connection.streams
+    }
+
+    /// Subscribe to more streams on the open connection.
+    ///
+    /// Events received while waiting for Binance to confirm go to the handler. Control
+    /// messages are throttled to the limit of 5 per second.
+    pub fn subscribe<S: Display>(&mut self, streams: &[S]) -> Result<()> {
+        self.subscribe_streams(streams.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// Unsubscribe from streams of the open connection.
+    pub fn unsubscribe<S: Display>(&mut self, streams: &[S]) -> Result<()> {
+        self.unsubscribe_streams(streams.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// Streams of the open connection, as reported by Binance.
+    pub fn list_subscriptions(&mut self) -> Result<Vec<String>> {
+        self.request_subscriptions()
+    }
+
+    /// Wrap events as `{"stream": ..., "data": ...}`, as on multi-stream connections.
+    pub fn set_combined(&mut self, combined: bool) -> Result<()> {
+        self.set_combined_property(combined)
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
@@ -174,4 +599,38 @@ impl<'a> WebSockets<'a> {
         }
         Ok(())
     }
+
+    /// Like `event_loop`, but reconnects to the same streams instead of returning an error
+    /// when the connection drops, and ahead of Binance closing it after 24 hours.
+    ///
+    /// Sends `Connected` to the handler on start, then `Disconnected` and `Reconnected`
+    /// around each reconnect. Returns an error once `policy.max_attempts` attempts in a row
+    /// failed, or if the handler fails.
+    pub fn event_loop_with_reconnect(
+        &mut self, running: &AtomicBool, policy: &ReconnectPolicy,
+    ) -> Result<()> {
+        self.supervise(running, policy)
+    }
+}
+
+impl StreamConnection for WebSockets<'_> {
+    fn socket(&mut self) -> &mut Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)> {
+        &mut self.socket
+    }
+
+    fn state(&mut self) -> &mut ConnectionState {
+        &mut self.connection
+    }
+
+    fn handle_text(&mut self, msg: &str) -> Result<()> {
+        self.handle_msg(msg)
+    }
+
+    fn handle_lifecycle(&mut self, event: Lifecycle) -> Result<()> {
+        (self.handler)(match event {
+            Lifecycle::Connected => WebsocketEvent::Connected,
+            Lifecycle::Disconnected(reason) => WebsocketEvent::Disconnected(reason),
+            Lifecycle::Reconnected { attempts } => WebsocketEvent::Reconnected { attempts },
+        })
+    }
 }
@@ -3,6 +3,7 @@ use binance::config::*;
 use binance::account::*;
 use binance::savings::*;
 use binance::model::*;
+use binance::futures::account::FuturesAccount;
 
 #[cfg(test)]
 mod tests {
@@ -997,4 +998,318 @@ mod tests {
     //     assert_eq!(position.auto_subscribe, Some(true));
     //     assert_eq!(position.can_re_stake, Some(true));
     // }
+
+    #[test]
+    fn preflight() {
+        let mut server = Server::new();
+        let mock_account_status = server
+            .mock("GET", "/sapi/v1/account/status")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/account_status.json")
+            .create();
+        let mock_trading_status = server
+            .mock("GET", "/sapi/v1/account/apiTradingStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/api_trading_status.json")
+            .create();
+        let mock_restrictions = server
+            .mock("GET", "/sapi/v1/account/apiRestrictions")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/api_restrictions.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let report = account.preflight().unwrap();
+
+        mock_account_status.assert();
+        mock_trading_status.assert();
+        mock_restrictions.assert();
+
+        assert_eq!(report.account_status, "Normal");
+        assert!(!report.trading_status.is_locked);
+        assert!(approx_eq!(
+            f64,
+            report.trading_status.trigger_condition["UFR"],
+            300.0,
+            ulps = 2
+        ));
+        assert!(!report.is_ready());
+        assert_eq!(
+            report.issues,
+            vec![
+                PreflightIssue::SpotTradingDisabled,
+                PreflightIssue::IpUnrestricted {
+                    trading_authority_expiration_time: Some(1628985600000)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn preflight_unrestricted_ip_is_a_warning() {
+        let mut server = Server::new();
+        let mock_account_status = server
+            .mock("GET", "/sapi/v1/account/status")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/account_status.json")
+            .create();
+        let mock_trading_status = server
+            .mock("GET", "/sapi/v1/account/apiTradingStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/api_trading_status.json")
+            .create();
+        let mock_restrictions = server
+            .mock("GET", "/sapi/v1/account/apiRestrictions")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"{"ipRestrict":false,"createTime":1698645219000,"enableReading":true,
+                "enableWithdrawals":false,"enableInternalTransfer":true,"enableMargin":false,
+                "enableFutures":false,"permitsUniversalTransfer":true,
+                "enableVanillaOptions":false,"enableSpotAndMarginTrading":true}"#,
+            )
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let report = account.preflight().unwrap();
+
+        mock_account_status.assert();
+        mock_trading_status.assert();
+        mock_restrictions.assert();
+
+        assert!(report.is_ready());
+        assert_eq!(
+            report.warnings,
+            vec![PreflightIssue::IpUnrestricted {
+                trading_authority_expiration_time: None
+            }]
+        );
+    }
+
+    #[test]
+    fn spot_snapshots() {
+        let mut server = Server::new();
+        let mock_snapshot = server
+            .mock("GET", "/sapi/v1/accountSnapshot")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=7&recvWindow=1234&timestamp=\\d+&type=SPOT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/spot_snapshot.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let snapshots = account
+            .spot_snapshots(SnapshotRequest {
+                limit: Some(7),
+                ..Default::default()
+            })
+            .unwrap();
+
+        mock_snapshot.assert();
+
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].snapshot_type, "spot");
+        assert_eq!(snapshots[0].update_time, 1576281599000);
+        assert_eq!(snapshots[0].data.balances[0].asset, "BTC");
+        assert!(approx_eq!(
+            f64,
+            snapshots[0].data.total_asset_of_btc,
+            0.099427,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn futures_snapshots() {
+        let mut server = Server::new();
+        let mock_snapshot = server
+            .mock("GET", "/sapi/v1/accountSnapshot")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&type=FUTURES&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/futures_snapshot.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let snapshots = account
+            .futures_snapshots(SnapshotRequest::default())
+            .unwrap();
+
+        mock_snapshot.assert();
+
+        let snapshot = &snapshots[0].data;
+        assert!(approx_eq!(
+            f64,
+            snapshot.assets[0].wallet_balance,
+            120.23811389,
+            ulps = 2
+        ));
+        assert_eq!(snapshot.position[0].symbol, "BTCUSDT");
+        assert!(approx_eq!(
+            f64,
+            snapshot.position[0].position_amount,
+            0.01,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn funding_assets() {
+        let mut server = Server::new();
+        let mock_funding_assets = server
+            .mock("POST", "/sapi/v1/asset/get-funding-asset")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "needBtcValuation=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/funding_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let assets = account.funding_assets(None, true).unwrap();
+
+        mock_funding_assets.assert();
+
+        assert_eq!(assets.len(), 2);
+        assert_eq!(assets[1].asset, "BTC");
+        assert!(approx_eq!(f64, assets[1].freeze, 0.1, ulps = 2));
+        assert_eq!(assets[1].ipoable, None);
+        assert_eq!(assets[0].btc_valuation, Some(0.00000091));
+    }
+
+    #[test]
+    fn user_assets() {
+        let mut server = Server::new();
+        let mock_user_assets = server
+            .mock("POST", "/sapi/v3/asset/getUserAsset")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BNB&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/user_assets.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let assets = account.user_assets(Some("BNB".into()), false).unwrap();
+
+        mock_user_assets.assert();
+
+        assert_eq!(assets.len(), 2);
+        assert!(approx_eq!(f64, assets[1].locked, 0.25, ulps = 2));
+        assert_eq!(assets[1].ipoable, Some(0.0));
+    }
+
+    #[test]
+    fn wallet_overview() {
+        let mut server = Server::new();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+        let mock_funding_assets = server
+            .mock("POST", "/sapi/v1/asset/get-funding-asset")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/funding_assets.json")
+            .create();
+        let mock_futures_balance = server
+            .mock("GET", "/fapi/v2/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/futures_balance.json")
+            .create();
+        let mock_flexible_positions = server
+            .mock("GET", "/sapi/v1/simple-earn/flexible/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "current=1&recvWindow=1234&size=100&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/flexible_product_position.json")
+            .create();
+        let mock_locked_positions = server
+            .mock("GET", "/sapi/v1/simple-earn/locked/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "current=1&recvWindow=1234&size=100&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/locked_product_position.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let futures_account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let overview = account.wallet_overview(&futures_account, &savings).unwrap();
+
+        mock_get_account.assert();
+        mock_funding_assets.assert();
+        mock_futures_balance.assert();
+        mock_flexible_positions.assert();
+        mock_locked_positions.assert();
+
+        assert_eq!(
+            overview.assets.keys().collect::<Vec<_>>(),
+            vec!["AXS", "BTC", "LTC", "USDT"]
+        );
+        let btc = overview.get("BTC").unwrap();
+        assert!(approx_eq!(f64, btc.spot, 4723846.89208129, ulps = 2));
+        assert!(approx_eq!(f64, btc.funding, 0.6, ulps = 2));
+        let usdt = overview.get("USDT").unwrap();
+        assert!(approx_eq!(f64, usdt.funding, 1.0, ulps = 2));
+        assert!(approx_eq!(f64, usdt.futures, 122.6, ulps = 2));
+        assert!(approx_eq!(f64, usdt.flexible_earn, 75.46, ulps = 2));
+        assert!(approx_eq!(f64, usdt.total(), 199.06, ulps = 2));
+        let axs = overview.get("AXS").unwrap();
+        assert!(approx_eq!(f64, axs.locked_earn, 122.09202928, ulps = 2));
+        assert!(overview.get("BNB").is_none());
+    }
 }
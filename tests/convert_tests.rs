@@ -0,0 +1,296 @@
+use binance::api::*;
+use binance::config::*;
+use binance::convert::*;
+use binance::errors::SdkError;
+use binance::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/sapi/v1/convert/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^fromAsset=BTC$".into()))
+            .with_body_from_file("tests/mocks/convert/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let pairs = convert.exchange_info(Some("BTC".into()), None).unwrap();
+
+        mock_exchange_info.assert();
+
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0].to_asset, "USDT");
+        assert!(approx_eq!(
+            f64,
+            pairs[0].from_asset_min_amount,
+            0.0004,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            pairs[0].to_asset_max_amount,
+            2500000.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_and_accept_quote() {
+        let mut server = Server::new();
+        let mock_get_quote = server
+            .mock("POST", "/sapi/v1/convert/getQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromAmount=0.1&fromAsset=BTC&recvWindow=1234&timestamp=\\d+&toAsset=USDT\
+                &validTime=30s&walletType=SPOT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/get_quote.json")
+            .create();
+        let mock_accept_quote = server
+            .mock("POST", "/sapi/v1/convert/acceptQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "quoteId=12415572564&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/accept_quote.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mut quote_request = ConvertQuoteRequest::new("BTC", "USDT");
+        quote_request.from_amount = Some(0.1);
+        quote_request.wallet_type = Some(ConvertWalletType::Spot);
+        quote_request.valid_time = Some(QuoteValidTime::ThirtySeconds);
+        let quote = convert.get_quote(quote_request).unwrap();
+        let accepted = convert.accept_quote(&quote).unwrap();
+
+        mock_get_quote.assert();
+        mock_accept_quote.assert();
+
+        assert_eq!(quote.quote_id, "12415572564");
+        assert!(approx_eq!(f64, quote.ratio, 38163.7, ulps = 2));
+        assert!(approx_eq!(f64, quote.to_amount, 3818.37, ulps = 2));
+        assert_eq!(accepted.order_id, 933256278426274426);
+        assert_eq!(accepted.order_status, "PROCESS");
+    }
+
+    #[test]
+    fn get_quote_requires_one_amount() {
+        let server = Server::new();
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+
+        let mut quote_request = ConvertQuoteRequest::new("BTC", "USDT");
+        quote_request.from_amount = Some(0.1);
+        quote_request.to_amount = Some(3818.37);
+
+        assert!(convert.get_quote(quote_request).is_err());
+    }
+
+    #[test]
+    fn accept_stale_quote() {
+        let mut server = Server::new();
+        let mock_accept_quote = server
+            .mock("POST", "/sapi/v1/convert/acceptQuote")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quote = ConvertQuote {
+            quote_id: "12415572564".into(),
+            ratio: 38163.7,
+            inverse_ratio: 0.0000262,
+            valid_timestamp: 1623319461670,
+            to_amount: 3818.37,
+            from_amount: 0.1,
+        };
+        let result = convert.accept_quote(&quote);
+
+        mock_accept_quote.assert();
+
+        match result {
+            Err(SdkError::QuoteExpired {
+                quote_id,
+                valid_timestamp,
+            }) => {
+                assert_eq!(quote_id, "12415572564");
+                assert_eq!(valid_timestamp, 1623319461670);
+            }
+            _ => panic!("expected a QuoteExpired error"),
+        }
+    }
+
+    #[test]
+    fn accept_quote_rejected() {
+        let mut server = Server::new();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quote = ConvertQuote {
+            quote_id: "12415572564".into(),
+            ratio: 38163.7,
+            inverse_ratio: 0.0000262,
+            valid_timestamp: 4102444800000,
+            to_amount: 3818.37,
+            from_amount: 0.1,
+        };
+
+        let mock_expired = server
+            .mock("POST", "/sapi/v1/convert/acceptQuote")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_body(format!(
+                r#"{{"code":{},"msg":"Quote has been expired"}}"#,
+                QUOTE_EXPIRED_CODE
+            ))
+            .create();
+        assert!(matches!(
+            convert.accept_quote(&quote),
+            Err(SdkError::QuoteExpired { .. })
+        ));
+        mock_expired.assert();
+        mock_expired.remove();
+
+        // Mentions expiry, but with another code
+        let mock_rejected = server
+            .mock("POST", "/sapi/v1/convert/acceptQuote")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_body(r#"{"code":-1021,"msg":"Timestamp for this request has expired."}"#)
+            .create();
+        match convert.accept_quote(&quote) {
+            Err(SdkError::BinanceError(content)) => assert_eq!(content.code, -1021),
+            _ => panic!("expected a BinanceError"),
+        }
+        mock_rejected.assert();
+    }
+
+    #[test]
+    fn order_status() {
+        let mut server = Server::new();
+        let mock_order_status = server
+            .mock("GET", "/sapi/v1/convert/orderStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=933256278426274426&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/order_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = convert.order_status(933256278426274426).unwrap();
+
+        mock_order_status.assert();
+
+        assert_eq!(order.order_status, "SUCCESS");
+        assert_eq!(order.quote_id, None);
+        assert!(approx_eq!(f64, order.from_amount, 0.1, ulps = 2));
+    }
+
+    #[test]
+    fn limit_order() {
+        let mut server = Server::new();
+        let mock_limit_order = server
+            .mock("POST", "/sapi/v1/convert/limit/placeOrder")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "baseAmount=0.1&baseAsset=BTC&expiredType=7_D&limitPrice=70000&quoteAsset=USDT\
+                &recvWindow=1234&side=SELL&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/limit_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = convert
+            .limit_order(LimitConvertOrderRequest {
+                base_asset: "BTC".into(),
+                quote_asset: "USDT".into(),
+                limit_price: 70000.0,
+                base_amount: Some(0.1),
+                quote_amount: None,
+                side: binance::account::OrderSide::Sell,
+                wallet_type: None,
+                expired_type: LimitOrderExpiry::SevenDays,
+            })
+            .unwrap();
+
+        mock_limit_order.assert();
+
+        assert_eq!(order.order_id, 1603680255057330400);
+        assert_eq!(order.quote_id, Some("18sdf87kh9df".into()));
+        assert_eq!(order.status, "PROCESS");
+    }
+
+    #[test]
+    fn trade_flow() {
+        let mut server = Server::new();
+        let mock_trade_flow = server
+            .mock("GET", "/sapi/v1/convert/tradeFlow")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1626416139000&limit=100&recvWindow=1234&startTime=1623824139000\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/convert/trade_flow.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let convert: Convert = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let trade_flow = convert
+            .trade_flow(1623824139000, 1626416139000, Some(100))
+            .unwrap();
+
+        mock_trade_flow.assert();
+
+        assert!(!trade_flow.more_data);
+        assert_eq!(trade_flow.list.len(), 1);
+        let trade = &trade_flow.list[0];
+        assert_eq!(
+            trade.quote_id,
+            Some("f3b91c525b2644c7bc1e1cd31b6e1aa6".into())
+        );
+        assert_eq!(trade.to_asset, "BNB");
+        assert!(approx_eq!(f64, trade.inverse_ratio, 324.99, ulps = 2));
+
+        assert!(convert
+            .trade_flow(
+                1623824139000,
+                1623824139000 + 31 * 24 * 60 * 60 * 1000,
+                None
+            )
+            .is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use binance::api::*;
+use binance::config::*;
+use binance::delivery::account::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+    use binance::futures::account::TimeInForce;
+
+    #[test]
+    fn limit_buy() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=9000&quantity=10&recvWindow=1234&side=BUY&symbol=BTCUSD_200925\
+                &timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/delivery/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_delivery_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: DeliveryAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account
+            .limit_buy("BTCUSD_200925", 10, 9000.0, TimeInForce::GTC)
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(order.symbol, "BTCUSD_200925");
+        assert_eq!(order.pair, "BTCUSD");
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.side, "BUY");
+        assert!(approx_eq!(f64, order.orig_qty, 10.0, ulps = 2));
+        assert!(approx_eq!(f64, order.cum_base, 0.0, ulps = 2));
+    }
+
+    #[test]
+    fn position_information() {
+        let mut server = Server::new();
+        let mock_position_information = server
+            .mock("GET", "/dapi/v1/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "pair=BTCUSD&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/delivery/account/position_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_delivery_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: DeliveryAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let positions = account
+            .position_information(Some("BTCUSD".to_string()))
+            .unwrap();
+
+        mock_position_information.assert();
+
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.symbol, "BTCUSD_201225");
+        assert!(!position.is_auto_add_margin);
+        assert!(approx_eq!(f64, position.position_amount, 3.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            position.notional_value,
+            0.01554584,
+            ulps = 2
+        ));
+    }
+}
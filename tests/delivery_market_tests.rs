@@ -0,0 +1,79 @@
+use binance::api::*;
+use binance::config::*;
+use binance::delivery::general::*;
+use binance::delivery::market::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/delivery/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_delivery_rest_api_endpoint(server.url());
+        let general: DeliveryGeneral = Binance::new_with_config(None, None, &config);
+
+        let symbol = general.get_symbol_info("btcusd_200925").unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(symbol.pair, "BTCUSD");
+        assert_eq!(symbol.margin_asset, "BTC");
+        assert!(approx_eq!(f64, symbol.contract_size, 100.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            symbol.notional_in_quote(3.0),
+            300.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            symbol.notional_in_base(3.0, 20_000.0),
+            0.015,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            symbol.contracts_for_base(0.0159, 20_000.0),
+            3.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_mark_price() {
+        let mut server = Server::new();
+        let mock_mark_price = server
+            .mock("GET", "/dapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_PERP".into()))
+            .with_body_from_file("tests/mocks/delivery/market/get_mark_price.json")
+            .create();
+
+        let config = Config::default().set_delivery_rest_api_endpoint(server.url());
+        let market: DeliveryMarket = Binance::new_with_config(None, None, &config);
+
+        let mark_prices = market.get_mark_price("BTCUSD_PERP").unwrap();
+        mock_mark_price.assert();
+
+        assert_eq!(mark_prices.len(), 2);
+        assert_eq!(mark_prices[0].pair, "BTCUSD");
+        assert_eq!(mark_prices[0].last_funding_rate, Some(0.00071003));
+        assert!(approx_eq!(
+            f64,
+            mark_prices[0].mark_price,
+            11029.69574559,
+            ulps = 2
+        ));
+        // Delivery contracts have no funding
+        assert_eq!(mark_prices[1].last_funding_rate, None);
+        assert_eq!(mark_prices[1].interest_rate, None);
+    }
+}
@@ -1,6 +1,8 @@
 use binance::api::*;
 use binance::config::*;
 use binance::futures::account::*;
+use binance::futures::income::*;
+use binance::futures::countdown::*;
 
 #[cfg(test)]
 mod tests {
@@ -8,6 +10,7 @@ mod tests {
     use mockito::{Server, Matcher};
     use float_cmp::*;
     use binance::account::OrderSide;
+    use binance::errors::SdkError;
     use binance::futures::model::Transaction;
 
     #[test]
@@ -212,6 +215,11 @@ mod tests {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            new_client_order_id: None,
+            self_trade_prevention_mode: None,
+            price_match: None,
+            good_till_date: None,
+            new_order_resp_type: None,
         };
         let transaction: Transaction = account.custom_order(custom_order).unwrap();
 
@@ -254,4 +262,738 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn custom_batch_orders() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "batchOrders=%5B%7B%22closePosition%22%3A%22TRUE%22.*%22symbol%22%3A%22SRMUSDT%22.*%7D%2C%7B.*%7D%5D\
+                &recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/custom_batch_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let close_order = |side: OrderSide, stop_price: f64| CustomOrderRequest {
+            symbol: "SRMUSDT".into(),
+            side,
+            position_side: None,
+            order_type: OrderType::StopMarket,
+            time_in_force: None,
+            qty: None,
+            reduce_only: None,
+            price: None,
+            stop_price: Some(stop_price),
+            close_position: Some(true),
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_client_order_id: None,
+            self_trade_prevention_mode: None,
+            price_match: None,
+            good_till_date: None,
+            new_order_resp_type: None,
+        };
+        let results = account
+            .custom_batch_orders(vec![
+                close_order(OrderSide::Buy, 10.5),
+                close_order(OrderSide::Sell, 7.4),
+            ])
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(results.len(), 2);
+        let transaction = results[0].as_ref().unwrap();
+        assert_eq!(transaction.symbol, "SRMUSDT");
+        assert!(approx_eq!(f64, transaction.stop_price, 10.5, ulps = 2));
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.code, -2022);
+    }
+
+    #[test]
+    fn custom_batch_orders_too_many() {
+        let account: FuturesAccount = Binance::new(None, None);
+        let orders = (0..6)
+            .map(|_| CustomOrderRequest {
+                symbol: "SRMUSDT".into(),
+                side: OrderSide::Buy,
+                position_side: None,
+                order_type: OrderType::Market,
+                time_in_force: None,
+                qty: Some(1.0),
+                reduce_only: None,
+                price: None,
+                stop_price: None,
+                close_position: None,
+                activation_price: None,
+                callback_rate: None,
+                working_type: None,
+                price_protect: None,
+                new_client_order_id: None,
+                self_trade_prevention_mode: None,
+                price_match: None,
+                good_till_date: None,
+                new_order_resp_type: None,
+            })
+            .collect();
+
+        assert!(account.custom_batch_orders(orders).is_err());
+    }
+
+    #[test]
+    fn cancel_multiple_orders() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("DELETE", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderIdList=%5B283194212%2C283194213%5D&recvWindow=1234\
+                &symbol=SRMUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/cancel_multiple_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .cancel_multiple_orders("SRMUSDT", vec![283194212, 283194213])
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(results[0].as_ref().unwrap().order_id, 283194212);
+        assert_eq!(results[0].as_ref().unwrap().status, "CANCELED");
+        assert_eq!(results[1].as_ref().unwrap_err().code, -2011);
+    }
+
+    #[test]
+    fn modify_order() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("PUT", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=20072994037&price=30005&quantity=1&recvWindow=1234\
+                &side=BUY&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account
+            .modify_order(ModifyOrderRequest {
+                symbol: "BTCUSDT".into(),
+                order_id: Some(20072994037),
+                orig_client_order_id: None,
+                side: OrderSide::Buy,
+                qty: 1.0,
+                price: Some(30005.0),
+                price_match: None,
+            })
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(order.order_id, 20072994037);
+        assert!(approx_eq!(f64, order.price, 30005.0, ulps = 2));
+        assert_eq!(order.price_match.as_deref(), Some("NONE"));
+    }
+
+    #[test]
+    fn modify_order_requires_one_price() {
+        let account: FuturesAccount = Binance::new(None, None);
+        let result = account.modify_order(ModifyOrderRequest {
+            symbol: "BTCUSDT".into(),
+            order_id: Some(20072994037),
+            orig_client_order_id: None,
+            side: OrderSide::Buy,
+            qty: 1.0,
+            price: Some(30005.0),
+            price_match: Some(PriceMatch::Queue),
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn get_order_modify_history() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/orderAmendment")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=20072994037&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_order_modify_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = account
+            .get_order_modify_history("BTCUSDT", 20072994037, None, None, None, None)
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].amendment_id, 5363);
+        assert_eq!(history[0].amendment.count, 3);
+        assert!(approx_eq!(
+            f64,
+            history[0].amendment.price.after,
+            30003.2,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_order_modify_history_without_order() {
+        let account: FuturesAccount = Binance::new(None, None);
+        let result = account.get_order_modify_history("BTCUSDT", None, None, None, None, None);
+        assert!(matches!(result, Err(SdkError::Other(_))));
+    }
+
+    #[test]
+    fn custom_order_builder() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "goodTillDate=1693207680000&newClientOrderId=myOrder1&newOrderRespType=RESULT\
+                &priceMatch=QUEUE&quantity=1&recvWindow=1234&selfTradePreventionMode=EXPIRE_MAKER\
+                &side=BUY&symbol=BTCUSDT&timeInForce=GTD&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/stop_market_close_position_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = CustomOrderRequest::builder("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_qty(1.0)
+            .set_price_match(PriceMatch::Queue)
+            .set_time_in_force(TimeInForce::GTD)
+            .set_good_till_date(1693207680000)
+            .set_new_client_order_id("myOrder1")
+            .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireMaker)
+            .set_new_order_resp_type(NewOrderResponseType::Result)
+            .build()
+            .unwrap();
+        account.custom_order(order).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn custom_order_builder_validation() {
+        let trailing_stop =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Sell, OrderType::TrailingStopMarket)
+                .set_qty(1.0)
+                .build();
+        assert!(trailing_stop.is_err());
+
+        let trailing_stop =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Sell, OrderType::TrailingStopMarket)
+                .set_qty(1.0)
+                .set_callback_rate(1.0)
+                .set_activation_price(31000.0)
+                .build();
+        assert!(trailing_stop.is_ok());
+
+        let limit_without_date =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                .set_qty(1.0)
+                .set_price(30000.0)
+                .set_time_in_force(TimeInForce::GTD)
+                .build();
+        assert!(limit_without_date.is_err());
+
+        let limit_with_both_prices =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                .set_qty(1.0)
+                .set_price(30000.0)
+                .set_price_match(PriceMatch::Opponent)
+                .set_time_in_force(TimeInForce::GTC)
+                .build();
+        assert!(limit_with_both_prices.is_err());
+
+        let close_with_qty =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Sell, OrderType::StopMarket)
+                .set_stop_price(29000.0)
+                .set_close_position(true)
+                .set_qty(1.0)
+                .build();
+        assert!(close_with_qty.is_err());
+
+        let close = CustomOrderRequest::builder("BTCUSDT", OrderSide::Sell, OrderType::StopMarket)
+            .set_stop_price(29000.0)
+            .set_close_position(true)
+            .set_price_protect(true)
+            .build();
+        assert!(close.is_ok());
+    }
+
+    #[test]
+    fn order_status_with_client_id() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=LJ9R4QZDihCaS8UAOOLpgW&recvWindow=1234&symbol=BTCUSDT\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account
+            .order_status_with_client_id("BTCUSDT", "LJ9R4QZDihCaS8UAOOLpgW".into())
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(order.order_id, 20072994037);
+        assert_eq!(order.self_trade_prevention_mode.as_deref(), Some("NONE"));
+    }
+
+    #[test]
+    fn get_open_order() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/openOrder")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=20072994037&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account.get_open_order("BTCUSDT", 20072994037).unwrap();
+
+        mock.assert();
+
+        assert_eq!(order.status, "NEW");
+    }
+
+    #[test]
+    fn get_position_mode() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/positionSide/dual")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let position_mode = account.get_position_mode().unwrap();
+
+        mock.assert();
+
+        assert!(position_mode.dual_side_position);
+    }
+
+    #[test]
+    fn get_adl_quantile() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/adlQuantile")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_adl_quantile.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quantiles = account.get_adl_quantile(None).unwrap();
+
+        mock.assert();
+
+        assert_eq!(quantiles.len(), 2);
+        assert_eq!(quantiles[0].adl_quantile.hedge, Some(0));
+        assert_eq!(quantiles[0].adl_quantile.both, None);
+        assert_eq!(quantiles[1].adl_quantile.short, Some(2));
+    }
+
+    #[test]
+    fn get_symbol_config() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/symbolConfig")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_symbol_config.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let configs = account.get_symbol_config(Some("BTCUSDT".into())).unwrap();
+
+        mock.assert();
+
+        assert_eq!(configs[0].margin_type, "CROSSED");
+        assert!(!configs[0].is_auto_add_margin);
+        assert_eq!(configs[0].leverage, 21);
+        assert!(approx_eq!(
+            f64,
+            configs[0].max_notional_value,
+            1_000_000.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_api_trading_status() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/apiTradingStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_api_trading_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let status = account.get_api_trading_status(None).unwrap();
+
+        mock.assert();
+
+        let account_indicators = &status.indicators["ACCOUNT"];
+        assert_eq!(account_indicators[0].indicator, "TMV");
+        assert!(account_indicators[0].is_locked);
+        assert_eq!(status.indicators["BTCUSDT"][0].indicator, "UFR");
+    }
+
+    #[test]
+    fn get_force_orders() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/forceOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "autoCloseType=LIQUIDATION&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_force_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders = account
+            .get_force_orders(
+                Some("BTCUSDT".into()),
+                Some(AutoCloseType::Liquidation),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].status, "FILLED");
+        assert!(approx_eq!(f64, orders[0].avg_price, 10913.21, ulps = 2));
+    }
+
+    #[test]
+    fn change_fee_burn() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/fapi/v1/feeBurn")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "feeBurn=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/change_position_mode.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        account.change_fee_burn(true).unwrap();
+
+        mock.assert();
+    }
+
+    #[test]
+    fn income_history_and_report() {
+        let mut server = Server::new();
+        let first_window = server
+            .mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1571212799999&limit=1000&recvWindow=1234&startTime=1570608000000\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_income_history.json")
+            .create();
+        let second_window = server
+            .mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1571299200000&limit=1000&recvWindow=1234&startTime=1571212800000\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/get_income_history_page.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let income_request = IncomeRequest {
+            symbol: None,
+            income_type: None,
+            start_time: Some(1570608000000),
+            end_time: Some(1571299200000),
+            limit: None,
+        };
+        let incomes = account
+            .income_history(income_request)
+            .collect::<binance::errors::Result<Vec<_>>>()
+            .unwrap();
+
+        first_window.assert();
+        second_window.assert();
+
+        assert_eq!(incomes.len(), 6);
+        assert_eq!(incomes[0].income_type, IncomeType::TRANSFER);
+        assert_eq!(incomes[5].income_type, IncomeType::UNKNOWN);
+
+        let report: IncomeReport = incomes.into_iter().collect();
+        assert!(approx_eq!(
+            f64,
+            report.total("BTCUSDT", "USDT", IncomeType::COMMISSION),
+            -0.26,
+            epsilon = 1e-9
+        ));
+        assert!(approx_eq!(
+            f64,
+            report.totals_by_asset(IncomeType::REALIZED_PNL)["USDT"],
+            12.5,
+            ulps = 2
+        ));
+        let net = report.net_by_symbol();
+        assert!(approx_eq!(
+            f64,
+            net[&("BTCUSDT".to_string(), "USDT".to_string())],
+            13.14,
+            epsilon = 1e-9
+        ));
+        // COMMISSION on 2019-10-09 and 2019-10-16, the rest on 2019-10-16
+        let btc_days: Vec<u64> = report
+            .entries()
+            .keys()
+            .filter(|key| key.symbol == "BTCUSDT")
+            .map(|key| key.day)
+            .collect();
+        assert_eq!(btc_days, vec![18178, 18185]);
+    }
+
+    #[test]
+    fn income_history_crowded_millisecond() {
+        let rows: Vec<serde_json::Value> = (0..1000)
+            .map(|tran_id| {
+                serde_json::json!({
+                    "symbol": "BTCUSDT",
+                    "incomeType": "COMMISSION",
+                    "income": "-0.01000000",
+                    "asset": "USDT",
+                    "info": "",
+                    "time": 1570608000000u64,
+                    "tranId": tran_id,
+                    "tradeId": ""
+                })
+            })
+            .collect();
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/income")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("startTime=1570608000000".into()))
+            .with_body(serde_json::Value::from(rows).to_string())
+            .expect(2)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let income_request = IncomeRequest {
+            symbol: None,
+            income_type: None,
+            start_time: Some(1570608000000),
+            end_time: Some(1570694400000),
+            limit: None,
+        };
+        let mut history = account.income_history(income_request);
+        let first_page = history
+            .by_ref()
+            .take(1000)
+            .filter(|row| row.is_ok())
+            .count();
+        assert_eq!(first_page, 1000);
+        // The second page returns the same rows, so the rest cannot be reached
+        assert!(history.next().unwrap().is_err());
+        assert!(history.next().is_none());
+        mock.assert();
+
+        let unknown = IncomeRequest {
+            symbol: None,
+            income_type: Some(IncomeType::UNKNOWN),
+            start_time: None,
+            end_time: None,
+            limit: None,
+        };
+        assert!(account.get_income(unknown).is_err());
+    }
+
+    #[test]
+    fn countdown_cancel_all() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=100000&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let countdown = account.countdown_cancel_all("BTCUSDT", 100000).unwrap();
+
+        mock.assert();
+
+        assert_eq!(countdown.symbol, "BTCUSDT");
+        assert_eq!(countdown.countdown_time, 100000);
+    }
+
+    #[test]
+    fn countdown_heartbeat() {
+        let mut server = Server::new();
+        let arm = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "countdownTime=10000&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/countdown_cancel_all.json")
+            .expect_at_least(2)
+            .create();
+        let reject = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=FOOUSDT".into()))
+            .with_body(r#"{"code":-1121,"msg":"Invalid symbol."}"#)
+            .expect_at_least(2)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let failures = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let failures_in_callback = failures.clone();
+        let mut heartbeat = CountdownHeartbeat::start(
+            account,
+            vec!["BTCUSDT".into(), "FOOUSDT".into()],
+            std::time::Duration::from_secs(10),
+            std::time::Duration::from_millis(20),
+            move |symbol, _error| {
+                failures_in_callback
+                    .lock()
+                    .unwrap()
+                    .push(symbol.to_string())
+            },
+        );
+        for _ in 0..5 {
+            heartbeat.beat();
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+        heartbeat.stop();
+
+        arm.assert();
+        reject.assert();
+
+        let failures = failures.lock().unwrap();
+        assert!(failures.len() >= 2);
+        assert!(failures.iter().all(|symbol| symbol == "FOOUSDT"));
+    }
 }
@@ -0,0 +1,145 @@
+use binance::api::*;
+use binance::config::*;
+use binance::futures::account::*;
+use binance::futures::download::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+    use std::fs::File;
+    use std::time::Duration;
+    use binance::errors::SdkError;
+
+    #[test]
+    fn parse_income() {
+        let file = File::open("tests/mocks/futures/download/income.csv").unwrap();
+        let incomes = parse_income_csv(file).unwrap();
+
+        assert_eq!(incomes.len(), 4);
+        assert_eq!(incomes[0].symbol, "");
+        assert_eq!(incomes[0].income_type, IncomeType::TRANSFER);
+        assert_eq!(incomes[0].tran_id, 9689322392);
+        assert_eq!(incomes[0].time, 1570608000000);
+        assert_eq!(incomes[1].time, 1570636800000);
+        assert_eq!(incomes[1].trade_id, "2059192");
+        assert_eq!(incomes[2].time, 1570636800000);
+        assert_eq!(incomes[3].income_type, IncomeType::FUNDING_FEE);
+        assert!(approx_eq!(f64, incomes[2].income, 12.5, ulps = 2));
+    }
+
+    #[test]
+    fn parse_orders() {
+        let file = File::open("tests/mocks/futures/download/orders.csv").unwrap();
+        let orders = parse_order_csv(file).unwrap();
+
+        assert_eq!(orders.len(), 2);
+        assert_eq!(orders[0].order_id, 8886774);
+        assert_eq!(orders[0].status, "FILLED");
+        assert_eq!(orders[0].position_side, "LONG");
+        assert!(approx_eq!(f64, orders[0].cum_quote, 300.0, ulps = 2));
+        assert!(approx_eq!(f64, orders[0].executed_qty, 0.01, ulps = 2));
+        assert_eq!(orders[1].order_type, "STOP_MARKET");
+        assert!(orders[1].reduce_only);
+        assert!(orders[1].price_protect);
+        assert!(approx_eq!(f64, orders[1].stop_price, 29000.0, ulps = 2));
+        assert_eq!(orders[1].update_time, 1629183600000);
+    }
+
+    #[test]
+    fn parse_trades() {
+        let file = File::open("tests/mocks/futures/download/trades.csv").unwrap();
+        let trades = parse_trade_csv(file).unwrap();
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].id, 698759);
+        assert_eq!(trades[0].order_id, 8886774);
+        assert!(trades[0].buyer);
+        assert!(trades[0].maker);
+        assert_eq!(trades[0].commission_asset, "USDT");
+        assert_eq!(trades[0].time, 1629182711000);
+        assert!(!trades[1].buyer);
+        assert!(approx_eq!(f64, trades[1].realized_pnl, 10.0, ulps = 2));
+        assert!(approx_eq!(f64, trades[1].quote_qty, 310.0, ulps = 2));
+    }
+
+    #[test]
+    fn parse_missing_required_column() {
+        let csv = "Symbol,Income,Asset\nBTCUSDT,1.0,USDT\n";
+        assert!(parse_income_csv(csv.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parse_missing_numeric_column() {
+        // No price column: the orders cannot be read as priced at zero
+        let csv = "orderId,clientOrderId,symbol,side,positionSide,type,status,avgPrice,origQty,\
+            executedQty,cumQuote,stopPrice,timeInForce,reduceOnly,closePosition,workingType,\
+            priceProtect,updateTime\n\
+            1,x,BTCUSDT,BUY,LONG,LIMIT,FILLED,1,1,1,1,0,GTC,false,false,MARK_PRICE,false,0\n";
+        assert!(matches!(
+            parse_order_csv(csv.as_bytes()),
+            Err(SdkError::Csv(_))
+        ));
+        // Nor without their position side
+        let with_price = csv
+            .replace("avgPrice", "price,avgPrice")
+            .replace("FILLED,", "FILLED,1,");
+        parse_order_csv(with_price.as_bytes()).unwrap();
+        let no_side = with_price.replace("positionSide,", "").replace("LONG,", "");
+        assert!(matches!(
+            parse_order_csv(no_side.as_bytes()),
+            Err(SdkError::Csv(_))
+        ));
+    }
+
+    #[test]
+    fn download_income() {
+        let mut server = Server::new();
+        let request_mock = server
+            .mock("GET", "/fapi/v1/income/asyn")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1570723200000&recvWindow=1234&startTime=1570579200000\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"avgCostTimestampOfLast30d":7241837,"downloadId":"546975389218332672"}"#)
+            .create();
+        let link_mock = server
+            .mock("GET", "/fapi/v1/income/asyn/id")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "downloadId=546975389218332672&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(format!(
+                r#"{{"downloadId":"546975389218332672","status":"completed","url":"{}/files/income.csv","notified":true,"expirationTimestamp":1645009771000,"isExpired":null}}"#,
+                server.url()
+            ))
+            .create();
+        let file_mock = server
+            .mock("GET", "/files/income.csv")
+            .with_body_from_file("tests/mocks/futures/download/income.csv")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let incomes = account
+            .download_income(
+                1570579200000,
+                1570723200000,
+                Duration::from_millis(10),
+                Duration::from_secs(1),
+            )
+            .unwrap();
+
+        request_mock.assert();
+        link_mock.assert();
+        file_mock.assert();
+
+        assert_eq!(incomes.len(), 4);
+    }
+}
@@ -7,6 +7,7 @@ use binance::futures::model::OpenInterestHist;
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
+    use float_cmp::*;
 
     #[test]
     fn open_interest_statistics() {
@@ -43,4 +44,53 @@ mod tests {
 
         assert_eq!(open_interest_hists, expectation);
     }
+
+    #[test]
+    fn get_leverage_brackets() {
+        let mut server = Server::new();
+        let mock_leverage_brackets = server
+            .mock("GET", "/fapi/v1/leverageBracket")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=ETHUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/leverage_brackets.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let brackets = market
+            .get_leverage_brackets(Some("ETHUSDT".to_string()))
+            .unwrap();
+        mock_leverage_brackets.assert();
+
+        assert_eq!(brackets.len(), 1);
+        let eth = &brackets[0];
+        assert_eq!(eth.symbol, "ETHUSDT");
+        assert_eq!(eth.notional_coef, Some(1.5));
+        assert_eq!(eth.brackets.len(), 3);
+
+        assert_eq!(eth.max_leverage(5_000.0), Some(75));
+        assert_eq!(eth.max_leverage(10_000.0), Some(50));
+        assert_eq!(eth.max_leverage(-20_000.0), Some(50));
+        assert_eq!(eth.max_leverage(1_000_000.0), None);
+
+        assert_eq!(eth.maintenance_margin_rate(100_000.0), Some(0.02));
+        assert!(approx_eq!(
+            f64,
+            eth.maintenance_amount(100_000.0).unwrap(),
+            1_465.0,
+            ulps = 2
+        ));
+        // Brackets are continuous at their boundaries
+        assert!(approx_eq!(
+            f64,
+            eth.brackets[1].maint_margin_ratio * 50_000.0 - eth.brackets[1].cum,
+            eth.brackets[2].maint_margin_ratio * 50_000.0 - eth.brackets[2].cum,
+            ulps = 2
+        ));
+    }
 }
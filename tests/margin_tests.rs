@@ -0,0 +1,250 @@
+use binance::api::*;
+use binance::config::*;
+use binance::margin::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn borrow_isolated() {
+        let mut server = Server::new();
+        let mock_borrow = server
+            .mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=1.5&asset=USDT&isIsolated=TRUE&recvWindow=1234&symbol=BTCUSDT\
+                &timestamp=\\d+&type=BORROW&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/borrow.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = margin.borrow("USDT", 1.5, Some("BTCUSDT".into())).unwrap();
+
+        mock_borrow.assert();
+
+        assert_eq!(transaction.tran_id, 100000001);
+    }
+
+    #[test]
+    fn market_buy_with_margin_buy() {
+        let mut server = Server::new();
+        let mock_market_buy = server
+            .mock("POST", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "quantity=0.01&recvWindow=1234&side=BUY&sideEffectType=MARGIN_BUY\
+                &symbol=BTCUSDT&timestamp=\\d+&type=MARKET&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/market_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = margin
+            .market_buy("BTCUSDT", 0.01, Some(SideEffectType::MarginBuy))
+            .unwrap();
+
+        mock_market_buy.assert();
+
+        assert_eq!(transaction.order_id, 28);
+        assert_eq!(transaction.is_isolated, Some(false));
+        assert!(approx_eq!(
+            f64,
+            transaction.margin_buy_borrow_amount.unwrap(),
+            500.0,
+            ulps = 2
+        ));
+        assert_eq!(transaction.margin_buy_borrow_asset, Some("USDT".into()));
+        assert_eq!(transaction.fills.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn oco_order() {
+        let mut server = Server::new();
+        let mock_oco_order = server
+            .mock("POST", "/sapi/v1/margin/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.036435&quantity=0.624363&recvWindow=1234&side=BUY\
+                &sideEffectType=MARGIN_BUY&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+\
+                &signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let oco_order = margin
+            .oco_order(MarginOcoRequest {
+                symbol: "LTCBTC".into(),
+                order_side: binance::account::OrderSide::Buy,
+                qty: 0.624363,
+                price: 0.036435,
+                stop_price: 0.960664,
+                stop_limit_price: None,
+                stop_limit_time_in_force: None,
+                list_client_order_id: None,
+                is_isolated: false,
+                side_effect_type: Some(SideEffectType::MarginBuy),
+            })
+            .unwrap();
+
+        mock_oco_order.assert();
+
+        assert_eq!(oco_order.order_list_id, 0);
+        assert_eq!(oco_order.contingency_type, "OCO");
+        assert_eq!(oco_order.orders.len(), 2);
+        assert_eq!(oco_order.order_reports[0].type_name, "STOP_LOSS");
+        assert!(approx_eq!(
+            f64,
+            oco_order.order_reports[0].stop_price,
+            0.960664,
+            ulps = 2
+        ));
+        assert_eq!(oco_order.margin_buy_borrow_asset, Some("BTC".into()));
+    }
+
+    #[test]
+    fn max_borrowable() {
+        let mut server = Server::new();
+        let mock_max_borrowable = server
+            .mock("GET", "/sapi/v1/margin/maxBorrowable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BTC&isolatedSymbol=BTCUSDT&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/max_borrowable.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let max_borrowable = margin
+            .max_borrowable("BTC", Some("BTCUSDT".into()))
+            .unwrap();
+
+        mock_max_borrowable.assert();
+
+        assert!(approx_eq!(f64, max_borrowable.amount, 1.69248805, ulps = 2));
+        assert!(approx_eq!(f64, max_borrowable.borrow_limit, 60.0, ulps = 2));
+    }
+
+    #[test]
+    fn interest_history() {
+        let mut server = Server::new();
+        let mock_interest_history = server
+            .mock("GET", "/sapi/v1/margin/interestHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=USDT&current=1&isolatedSymbol=BNBUSDT&recvWindow=1234&size=10\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/interest_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = margin
+            .interest_history(InterestHistoryRequest {
+                asset: Some("USDT".into()),
+                isolated_symbol: Some("BNBUSDT".into()),
+                current: Some(1),
+                size: Some(10),
+                ..Default::default()
+            })
+            .unwrap();
+
+        mock_interest_history.assert();
+
+        assert_eq!(history.total, 1);
+        let record = &history.data[0];
+        assert_eq!(record.interest_type, "ON_BORROW");
+        assert_eq!(record.interest_accured_time, 1672160400000);
+        assert!(approx_eq!(f64, record.interest, 0.00024995, ulps = 2));
+        assert_eq!(record.isolated_symbol, Some("BNBUSDT".into()));
+    }
+
+    #[test]
+    fn cross_account() {
+        let mut server = Server::new();
+        let mock_cross_account = server
+            .mock("GET", "/sapi/v1/margin/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/cross_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin.cross_account().unwrap();
+
+        mock_cross_account.assert();
+
+        assert!(account.borrow_enabled);
+        assert!(approx_eq!(f64, account.margin_level, 11.64405625, ulps = 2));
+        assert_eq!(account.user_assets.len(), 2);
+        let usdt = &account.user_assets[1];
+        assert_eq!(usdt.asset, "USDT");
+        assert!(approx_eq!(f64, usdt.borrowed, 500.0, ulps = 2));
+        assert!(approx_eq!(f64, usdt.net_asset, 499.975, ulps = 2));
+    }
+
+    #[test]
+    fn isolated_account() {
+        let mut server = Server::new();
+        let mock_isolated_account = server
+            .mock("GET", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbols=BTCUSDT,ETHUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/isolated_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let margin: Margin = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = margin.isolated_account(&["BTCUSDT", "ETHUSDT"]).unwrap();
+
+        mock_isolated_account.assert();
+
+        assert_eq!(account.assets.len(), 1);
+        assert_eq!(account.total_asset_of_btc, None);
+        let pair = &account.assets[0];
+        assert_eq!(pair.symbol, "BTCUSDT");
+        assert_eq!(pair.base_asset.asset, "BTC");
+        assert_eq!(pair.quote_asset.asset, "USDT");
+        assert_eq!(pair.margin_level_status, "EXCESSIVE");
+    }
+}
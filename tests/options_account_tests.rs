@@ -0,0 +1,112 @@
+use binance::api::*;
+use binance::config::*;
+use binance::options::account::*;
+use binance::options::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+    use binance::futures::account::TimeInForce;
+
+    #[test]
+    fn limit_buy() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/eapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=2800&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTC-240628-60000-C\
+                &timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/options/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = account
+            .limit_buy("BTC-240628-60000-C", 0.5, 2800.0, TimeInForce::GTC)
+            .unwrap();
+
+        mock_limit_buy.assert();
+
+        assert_eq!(order.order_id, 4611875134427365377);
+        assert_eq!(order.symbol, "BTC-240628-60000-C");
+        assert_eq!(order.status, "ACCEPTED");
+        assert_eq!(order.option_side, OptionSide::Call);
+        assert!(approx_eq!(f64, order.quantity, 0.5, ulps = 2));
+    }
+
+    #[test]
+    fn position_information() {
+        let mut server = Server::new();
+        let mock_position_information = server
+            .mock("GET", "/eapi/v1/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/account/position_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let positions = account.position_information(None).unwrap();
+
+        mock_position_information.assert();
+
+        assert_eq!(positions.len(), 1);
+        let position = &positions[0];
+        assert_eq!(position.side, PositionSide::Long);
+        assert_eq!(position.option_side, OptionSide::Call);
+        assert_eq!(position.expiry_date, 1719561600000);
+        assert!(approx_eq!(f64, position.strike_price, 60000.0, ulps = 2));
+        assert!(approx_eq!(f64, position.unrealized_pnl, 50.0, ulps = 2));
+    }
+
+    #[test]
+    fn account_information() {
+        let mut server = Server::new();
+        let mock_account_information = server
+            .mock("GET", "/eapi/v1/marginAccount")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/account/account_information.json")
+            .create();
+
+        let config = Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account_information = account.account_information().unwrap();
+
+        mock_account_information.assert();
+
+        assert!(account_information.can_trade);
+        assert_eq!(account_information.asset[0].asset, "USDT");
+        assert!(approx_eq!(
+            f64,
+            account_information.asset[0].unrealized_pnl,
+            -5.00138,
+            ulps = 2
+        ));
+        assert_eq!(account_information.greek[0].underlying, "BTCUSDT");
+        assert!(approx_eq!(
+            f64,
+            account_information.greek[0].delta,
+            0.23766,
+            ulps = 2
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+use binance::api::*;
+use binance::config::*;
+use binance::options::general::*;
+use binance::options::market::*;
+use binance::options::model::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/eapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/options/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(server.url());
+        let general: OptionsGeneral = Binance::new_with_config(None, None, &config);
+
+        let symbol = general.get_symbol_info("btc-240628-60000-p").unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(symbol.underlying, "BTCUSDT");
+        assert_eq!(symbol.side, OptionSide::Put);
+        assert!(symbol.is_put());
+        assert_eq!(symbol.expiry_date, 1719561600000);
+        assert!(!symbol.is_expired(1719561599999));
+        assert!(symbol.is_expired(1719561600000));
+        assert!(approx_eq!(f64, symbol.strike_price, 60000.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            symbol.intrinsic_value(58_500.0),
+            1500.0,
+            ulps = 2
+        ));
+        assert!(approx_eq!(
+            f64,
+            symbol.intrinsic_value(61_000.0),
+            0.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn get_mark_price() {
+        let mut server = Server::new();
+        let mock_mark_price = server
+            .mock("GET", "/eapi/v1/mark")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTC-240628-60000-C".into()))
+            .with_body_from_file("tests/mocks/options/market/get_mark_price.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(server.url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mark_prices = market.get_mark_price("BTC-240628-60000-C").unwrap();
+        mock_mark_price.assert();
+
+        assert_eq!(mark_prices.len(), 1);
+        let mark = &mark_prices[0];
+        assert!(approx_eq!(f64, mark.mark_price, 2850.0, ulps = 2));
+        assert!(approx_eq!(f64, mark.mark_iv, 0.5426, ulps = 2));
+        assert!(approx_eq!(f64, mark.delta, 0.47532, ulps = 2));
+        assert!(approx_eq!(f64, mark.theta, -61.38571, ulps = 2));
+        assert!(approx_eq!(f64, mark.vega, 71.45132, ulps = 2));
+    }
+
+    #[test]
+    fn get_depth() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/eapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=10&symbol=BTC-240628-60000-C".into()))
+            .with_body_from_file("tests/mocks/options/market/get_depth.json")
+            .create();
+
+        let config = Config::default().set_options_rest_api_endpoint(server.url());
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_book = market.get_custom_depth("BTC-240628-60000-C", 10).unwrap();
+        mock_depth.assert();
+
+        assert_eq!(order_book.update_id, 37461);
+        assert_eq!(order_book.bids.len(), 2);
+        assert_eq!(order_book.bids[0], Bids::new(2840.0, 1.25));
+        assert!(approx_eq!(f64, order_book.asks[0].qty, 0.8, ulps = 2));
+    }
+}
@@ -0,0 +1,219 @@
+use binance::api::*;
+use binance::config::*;
+use binance::futures::market::FuturesMarket;
+use binance::market::*;
+use binance::model::DepthOrderBookEvent;
+use binance::order_book::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+    use serde_json::json;
+    use std::thread;
+    use std::time::Duration;
+
+    fn depth_event(
+        first_update_id: u64, final_update_id: u64, previous_final_update_id: Option<u64>,
+        bids: serde_json::Value, asks: serde_json::Value,
+    ) -> DepthOrderBookEvent {
+        serde_json::from_value(json!({
+            "e": "depthUpdate",
+            "E": 1672515782136u64,
+            "s": "BTCUSDT",
+            "U": first_update_id,
+            "u": final_update_id,
+            "pu": previous_final_update_id,
+            "b": bids,
+            "a": asks
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn spot_sync_and_gap() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=1000&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/order_book/spot_depth.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mut updates = 0;
+        let mut book = LocalOrderBook::spot(market, "BTCUSDT", 1000);
+        book.on_update(|_| updates += 1);
+
+        // Older than the snapshot, dropped
+        book.apply(depth_event(150, 160, None, json!([]), json!([])))
+            .unwrap();
+        assert!(!book.is_synced());
+        assert!(book.best_bid().is_none());
+
+        book.apply(depth_event(
+            158,
+            162,
+            None,
+            json!([["100.00", "0"]]),
+            json!([["101.00", "5.0"]]),
+        ))
+        .unwrap();
+        assert!(book.is_synced());
+        assert_eq!(book.last_update_id(), 162);
+        let best_bid = book.best_bid().unwrap();
+        assert!(approx_eq!(f64, best_bid.price, 99.0, ulps = 2));
+        assert!(approx_eq!(
+            f64,
+            book.ask_qty_at(101.0).unwrap(),
+            5.0,
+            ulps = 2
+        ));
+        assert_eq!(book.bid_qty_at(100.0), None);
+
+        book.apply(depth_event(
+            163,
+            165,
+            None,
+            json!([["99.50", "4.0"]]),
+            json!([]),
+        ))
+        .unwrap();
+        let bids = book.bids(5);
+        assert_eq!(bids.len(), 2);
+        assert!(approx_eq!(f64, bids[0].price, 99.5, ulps = 2));
+        assert!(approx_eq!(f64, bids[1].price, 99.0, ulps = 2));
+        let asks = book.asks(1);
+        assert!(approx_eq!(f64, asks[0].price, 101.0, ulps = 2));
+
+        // Updates 166 to 169 are missing
+        book.apply(depth_event(170, 172, None, json!([]), json!([])))
+            .unwrap();
+        assert!(!book.is_synced());
+        assert_eq!(book.resyncs(), 1);
+        assert!(book.best_ask().is_none());
+        drop(book);
+
+        mock_depth.assert();
+        assert_eq!(updates, 2);
+    }
+
+    #[test]
+    fn usdm_futures_sync_and_gap() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/fapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=500&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/order_book/futures_depth.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mut book = LocalOrderBook::usdm_futures(market, "BTCUSDT", 500);
+
+        book.apply(depth_event(190, 199, Some(189), json!([]), json!([])))
+            .unwrap();
+        assert!(!book.is_synced());
+
+        book.apply(depth_event(
+            198,
+            205,
+            Some(199),
+            json!([]),
+            json!([["101.00", "0"]]),
+        ))
+        .unwrap();
+        assert!(book.is_synced());
+        let best_ask = book.best_ask().unwrap();
+        assert!(approx_eq!(f64, best_ask.price, 102.0, ulps = 2));
+        assert!(approx_eq!(f64, best_ask.qty, 3.0, ulps = 2));
+
+        book.apply(depth_event(
+            206,
+            210,
+            Some(205),
+            json!([["100.50", "7.0"]]),
+            json!([]),
+        ))
+        .unwrap();
+        assert!(approx_eq!(
+            f64,
+            book.best_bid().unwrap().price,
+            100.5,
+            ulps = 2
+        ));
+        assert_eq!(book.last_update_id(), 210);
+
+        // Events of other symbols are ignored
+        let mut other = depth_event(1, 2, Some(0), json!([]), json!([]));
+        other.symbol = "ETHUSDT".into();
+        book.apply(other).unwrap();
+        assert!(book.is_synced());
+
+        // The previous final update id does not chain to 210
+        book.apply(depth_event(211, 215, Some(209), json!([]), json!([])))
+            .unwrap();
+        assert!(!book.is_synced());
+        assert_eq!(book.resyncs(), 1);
+
+        mock_depth.assert();
+    }
+
+    #[test]
+    fn spot_stale_snapshots() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=1000&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/order_book/spot_depth.json")
+            .expect(5)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let mut book = LocalOrderBook::spot(market, "BTCUSDT", 1000);
+
+        // The snapshot stops at 160, before the first buffered event
+        book.apply(depth_event(170, 172, None, json!([]), json!([])))
+            .unwrap();
+        // Buffered without fetching again right away
+        book.apply(depth_event(173, 175, None, json!([]), json!([])))
+            .unwrap();
+        assert!(!book.is_synced());
+
+        let mut first_update_id = 176;
+        for _ in 0..3 {
+            thread::sleep(Duration::from_millis(600));
+            book.apply(depth_event(
+                first_update_id,
+                first_update_id + 2,
+                None,
+                json!([]),
+                json!([]),
+            ))
+            .unwrap();
+            first_update_id += 3;
+        }
+        thread::sleep(Duration::from_millis(600));
+        assert!(book
+            .apply(depth_event(
+                first_update_id,
+                first_update_id + 2,
+                None,
+                json!([]),
+                json!([])
+            ))
+            .is_err());
+        assert!(!book.is_synced());
+
+        mock_depth.assert();
+    }
+}
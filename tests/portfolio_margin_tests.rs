@@ -0,0 +1,224 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::OrderSide;
+use binance::futures::account::*;
+use binance::portfolio_margin::account::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    fn portfolio_margin(server: &Server) -> PortfolioMargin {
+        let config = Config::default()
+            .set_portfolio_margin_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        Binance::new_with_config(None, None, &config)
+    }
+
+    #[test]
+    fn um_custom_order() {
+        let mut server = Server::new();
+        let mock_order = server
+            .mock("POST", "/papi/v1/um/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=30000&quantity=0.01&recvWindow=1234&side=BUY&symbol=BTCUSDT\
+                &timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/um_order.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let order_request =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                .set_qty(0.01)
+                .set_price(30000.0)
+                .set_time_in_force(TimeInForce::GTC)
+                .build()
+                .unwrap();
+        let order = portfolio_margin.um_custom_order(order_request).unwrap();
+
+        mock_order.assert();
+
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.order_type, "LIMIT");
+        assert_eq!(order.cum_quote, Some(0.0));
+        assert_eq!(order.cum_base, None);
+        assert!(approx_eq!(f64, order.price, 30000.0, ulps = 2));
+    }
+
+    #[test]
+    fn um_custom_order_rejects_conditional_type() {
+        let server = Server::new();
+        let portfolio_margin = portfolio_margin(&server);
+        let order_request =
+            CustomOrderRequest::builder("BTCUSDT", OrderSide::Sell, OrderType::StopMarket)
+                .set_stop_price(25000.0)
+                .set_close_position(true)
+                .build()
+                .unwrap();
+
+        assert!(portfolio_margin.um_custom_order(order_request).is_err());
+    }
+
+    #[test]
+    fn cm_conditional_order() {
+        let mut server = Server::new();
+        let mock_order = server
+            .mock("POST", "/papi/v1/cm/conditional/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientStrategyId=stopLoss1&quantity=10&recvWindow=1234&reduceOnly=TRUE\
+                &side=SELL&stopPrice=25000&strategyType=STOP_MARKET&symbol=BTCUSD_PERP\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/cm_conditional_order.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let order_request =
+            CustomOrderRequest::builder("BTCUSD_PERP", OrderSide::Sell, OrderType::StopMarket)
+                .set_qty(10.0)
+                .set_stop_price(25000.0)
+                .set_reduce_only(true)
+                .set_new_client_order_id("stopLoss1")
+                .build()
+                .unwrap();
+        let order = portfolio_margin
+            .cm_conditional_order(order_request)
+            .unwrap();
+
+        mock_order.assert();
+
+        assert_eq!(order.strategy_id, 123445);
+        assert_eq!(order.strategy_type, "STOP_MARKET");
+        assert_eq!(order.pair, Some("BTCUSD".into()));
+        assert_eq!(order.stop_price, Some(25000.0));
+        assert_eq!(order.activate_price, None);
+    }
+
+    #[test]
+    fn cm_position_information() {
+        let mut server = Server::new();
+        let mock_position = server
+            .mock("GET", "/papi/v1/cm/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "pair=BTCUSD&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/position_risk.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let positions = portfolio_margin
+            .cm_position_information(Some("BTCUSD".into()))
+            .unwrap();
+
+        mock_position.assert();
+
+        assert_eq!(positions.len(), 1);
+        assert!(approx_eq!(f64, positions[0].position_amount, 3.0, ulps = 2));
+        assert_eq!(positions[0].max_qty, Some(50.0));
+        assert_eq!(positions[0].max_notional_value, None);
+    }
+
+    #[test]
+    fn margin_borrow() {
+        let mut server = Server::new();
+        let mock_loan = server
+            .mock("POST", "/papi/v1/marginLoan")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=100.5&asset=USDT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/margin_loan.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let transaction = portfolio_margin.margin_borrow("USDT", 100.5).unwrap();
+
+        mock_loan.assert();
+
+        assert_eq!(transaction.tran_id, 100000001);
+    }
+
+    #[test]
+    fn get_balance() {
+        let mut server = Server::new();
+        let mock_balance = server
+            .mock("GET", "/papi/v1/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=USDT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/balance.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let balances = portfolio_margin.get_balance(Some("USDT".into())).unwrap();
+
+        mock_balance.assert();
+
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].asset, "USDT");
+        assert!(approx_eq!(
+            f64,
+            balances[0].total_wallet_balance,
+            122607.35137903,
+            ulps = 2
+        ));
+        assert_eq!(balances[0].um_unrealized_pnl, Some(23.72469206));
+        assert_eq!(balances[0].cm_unrealized_pnl, None);
+    }
+
+    #[test]
+    fn account_information() {
+        let mut server = Server::new();
+        let mock_account = server
+            .mock("GET", "/papi/v1/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/account.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        let account = portfolio_margin.account_information().unwrap();
+
+        mock_account.assert();
+
+        assert!(approx_eq!(f64, account.uni_mmr, 5167.92171923, ulps = 2));
+        assert_eq!(account.account_status, "NORMAL");
+        assert_eq!(account.total_available_balance, None);
+    }
+
+    #[test]
+    fn fund_collection_by_asset() {
+        let mut server = Server::new();
+        let mock_collection = server
+            .mock("POST", "/papi/v1/asset-collection")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=USDT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/portfolio_margin/auto_collection.json")
+            .create();
+
+        let portfolio_margin = portfolio_margin(&server);
+        let _ = env_logger::try_init();
+        portfolio_margin.fund_collection_by_asset("USDT").unwrap();
+
+        mock_collection.assert();
+    }
+}
@@ -0,0 +1,705 @@
+use binance::api::*;
+use binance::config::*;
+use binance::market::*;
+use binance::model::*;
+use binance::savings::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn transfer_funds() {
+        let mut server = Server::new();
+        let mock_transfer = server
+            .mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=10.5&asset=USDT&fromSymbol=BTCUSDT&recvWindow=1234&timestamp=\\d+\
+                &type=ISOLATEDMARGIN_MARGIN&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transaction = savings
+            .transfer_funds(
+                "USDT",
+                10.5,
+                UniversalTransferType::ISOLATEDMARGIN_MARGIN,
+                Some("BTCUSDT".into()),
+                None,
+            )
+            .unwrap();
+
+        mock_transfer.assert();
+
+        assert_eq!(transaction.tran_id, 13526853623);
+    }
+
+    #[test]
+    fn transfer_funds_requires_isolated_symbol() {
+        let server = Server::new();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+
+        let result = savings.transfer_funds(
+            "USDT",
+            10.5,
+            UniversalTransferType::MARGIN_ISOLATEDMARGIN,
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn transfer_history() {
+        let mut server = Server::new();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "current=2&recvWindow=1234&size=100&startTime=1544400000000&timestamp=\\d+\
+                &type=MAIN_UMFUTURE&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history_request = TransferHistoryRequest {
+            start_time: Some(1544400000000),
+            current: Some(2),
+            size: Some(100),
+            ..TransferHistoryRequest::new(UniversalTransferType::MAIN_UMFUTURE)
+        };
+        let history = savings.transfer_history(history_request).unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(history.total, 2);
+        assert_eq!(history.data.len(), 2);
+        let transfer = &history.data[0];
+        assert_eq!(transfer.transfer_type, UniversalTransferType::MAIN_UMFUTURE);
+        assert_eq!(transfer.status, "CONFIRMED");
+        assert_eq!(transfer.tran_id, 11415955596);
+        assert!(approx_eq!(f64, transfer.amount, 1.0, ulps = 2));
+    }
+
+    fn btc_networks() -> Vec<Network> {
+        let coins: Vec<CoinInfo> =
+            serde_json::from_str(include_str!("mocks/savings/all_coins.json")).unwrap();
+        coins[0].network_list.clone()
+    }
+
+    #[test]
+    fn validate_withdrawal() {
+        let networks = btc_networks();
+        let (bep2, btc) = (&networks[0], &networks[1]);
+        let address = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlhqwertyuiopasdfg";
+
+        assert!(btc.validate_withdrawal(address, None, 0.0015).is_ok());
+        // Invalid address
+        assert!(btc
+            .validate_withdrawal("0x94df8b352de7f46f64b01d3666bf6e936e44ce60", None, 0.0015)
+            .is_err());
+        // BTC network takes no memo
+        assert!(btc
+            .validate_withdrawal(address, Some("1234"), 0.0015)
+            .is_err());
+        // Below withdrawMin
+        assert!(btc.validate_withdrawal(address, None, 0.0005).is_err());
+        // Not a multiple of withdrawIntegerMultiple
+        assert!(btc.validate_withdrawal(address, None, 0.001000005).is_err());
+        // Withdrawals disabled
+        assert!(bep2
+            .validate_withdrawal(
+                "bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23",
+                Some("1"),
+                0.01
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn withdraw() {
+        let mut server = Server::new();
+        let mock_coins = server
+            .mock("GET", "/sapi/v1/capital/config/getall")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/all_coins.json")
+            .create();
+        let mock_withdraw = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "address=1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2&amount=0.002&coin=BTC&network=BTC\
+                &recvWindow=1234&timestamp=\\d+&withdrawOrderId=payout-42&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let withdraw_request = WithdrawRequest {
+            network: Some("BTC".into()),
+            withdraw_order_id: Some("payout-42".into()),
+            ..WithdrawRequest::new("BTC", "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", 0.002)
+        };
+        let response = savings.withdraw(withdraw_request).unwrap();
+
+        mock_coins.assert();
+        mock_withdraw.assert();
+
+        assert_eq!(response.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn withdraw_with_coin_info() {
+        let mut server = Server::new();
+        let mock_coins = server
+            .mock("GET", "/sapi/v1/capital/config/getall")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+        let mock_withdraw = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "address=1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2&amount=0.002&coin=BTC\
+                &name=Cold\\+wallet\\+%26\\+co&network=BTC&recvWindow=1234&timestamp=\\d+\
+                &withdrawOrderId=a%3Db%2Bc&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mut coins: Vec<CoinInfo> =
+            serde_json::from_str(include_str!("mocks/savings/all_coins.json")).unwrap();
+        // Lookahead is not supported by the regex crate, the address is not checked
+        coins[0].network_list[1].address_regex = "^(?=1|3|bc1)[a-zA-Z0-9]{25,62}$".into();
+        let withdraw_request = WithdrawRequest {
+            network: Some("BTC".into()),
+            withdraw_order_id: Some("a=b+c".into()),
+            name: Some("Cold wallet & co".into()),
+            ..WithdrawRequest::new("BTC", "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2", 0.002)
+        };
+        let response = savings
+            .withdraw_with_coin_info(withdraw_request, &coins[0])
+            .unwrap();
+
+        mock_coins.assert();
+        mock_withdraw.assert();
+
+        assert_eq!(response.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn withdraw_rejects_invalid_address() {
+        let mut server = Server::new();
+        let mock_coins = server
+            .mock("GET", "/sapi/v1/capital/config/getall")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/savings/all_coins.json")
+            .create();
+        let mock_withdraw = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let result = savings.withdraw(WithdrawRequest::new("BTC", "not-an-address", 0.002));
+
+        mock_coins.assert();
+        mock_withdraw.assert();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deposit_history() {
+        let mut server = Server::new();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "coin=BNB&limit=100&recvWindow=1234&status=1&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/deposit_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = savings
+            .deposit_history(DepositHistoryRequest {
+                coin: Some("BNB".into()),
+                status: Some(1),
+                limit: Some(100),
+                ..Default::default()
+            })
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].address_tag, "101764890");
+        assert_eq!(history[0].confirm_times, "1/1");
+        assert!(approx_eq!(f64, history[0].amount, 0.001, ulps = 2));
+    }
+
+    #[test]
+    fn withdraw_history() {
+        let mut server = Server::new();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&withdrawOrderId=WITHDRAWtest123&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let history = savings
+            .withdraw_history(WithdrawHistoryRequest {
+                withdraw_order_id: Some("WITHDRAWtest123".into()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].status, 6);
+        assert_eq!(history[0].withdraw_order_id, Some("WITHDRAWtest123".into()));
+        assert!(approx_eq!(f64, history[0].transaction_fee, 0.004, ulps = 2));
+    }
+
+    #[test]
+    fn deposit_addresses() {
+        let mut server = Server::new();
+        let mock_addresses = server
+            .mock("GET", "/sapi/v1/capital/deposit/address/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "coin=ETH&network=ETH&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/deposit_addresses.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let addresses = savings
+            .deposit_addresses("ETH", Some("ETH".into()))
+            .unwrap();
+
+        mock_addresses.assert();
+
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].is_default, 1);
+    }
+
+    #[test]
+    fn paginated_response_pages() {
+        let mut requested = Vec::new();
+        let rows: Vec<u32> = PaginatedResponse::pages(2, |current, size| {
+            requested.push((current, size));
+            let data = match current {
+                1 => vec![1, 2],
+                2 => vec![3, 4],
+                _ => vec![5],
+            };
+            Ok(PaginatedResponse { data, total: 5 })
+        })
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+        assert_eq!(rows, vec![1, 2, 3, 4, 5]);
+        assert_eq!(requested, vec![(1, 2), (2, 2), (3, 2)]);
+    }
+
+    #[test]
+    fn simple_earn_flexible_products() {
+        let mut server = Server::new();
+        let mock_products = server
+            .mock("GET", "/sapi/v1/simple-earn/flexible/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BTC&current=1&recvWindow=1234&size=100&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/flexible_products.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let products = savings
+            .simple_earn_flexible_products(Some("BTC".into()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        mock_products.assert();
+
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].product_id, "BTC001");
+        assert_eq!(
+            products[0].tier_annual_percentage_rate.get("0-5BTC"),
+            Some(&0.05)
+        );
+        assert!(approx_eq!(
+            f64,
+            products[0].min_purchase_amount,
+            0.01,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn subscribe_flexible_product() {
+        let mut server = Server::new();
+        let mock_subscribe = server
+            .mock("POST", "/sapi/v1/simple-earn/flexible/subscribe")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=0.5&autoSubscribe=false&productId=BTC001&recvWindow=1234\
+                &sourceAccount=FUND&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/subscribe.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let subscription = savings
+            .subscribe_flexible_product("BTC001", 0.5, Some(false), Some(EarnSourceAccount::Fund))
+            .unwrap();
+
+        mock_subscribe.assert();
+
+        assert_eq!(subscription.purchase_id, 40607);
+        assert!(subscription.success);
+        assert_eq!(subscription.position_id, None);
+    }
+
+    #[test]
+    fn redeem_flexible_product() {
+        let mut server = Server::new();
+        let mock_redeem = server
+            .mock("POST", "/sapi/v1/simple-earn/flexible/redeem")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "productId=BTC001&recvWindow=1234&redeemAll=true&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/redeem.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let redemption = savings.redeem_flexible_product("BTC001", None).unwrap();
+
+        mock_redeem.assert();
+
+        assert_eq!(redemption.redeem_id, 40607);
+    }
+
+    #[test]
+    fn locked_rewards_history() {
+        let mut server = Server::new();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/simple-earn/locked/history/rewardsRecord")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BNB&current=1&recvWindow=1234&size=100&startTime=1575000000000\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/locked_rewards_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let rewards = savings
+            .locked_rewards_history(EarnHistoryRequest {
+                asset: Some("BNB".into()),
+                start_time: Some(1575000000000),
+                ..Default::default()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].position_id, 123123);
+        assert_eq!(rewards[0].lock_period, 30);
+        assert!(approx_eq!(f64, rewards[0].amount, 21312.23223, ulps = 2));
+    }
+
+    #[test]
+    fn flexible_personal_left_quota() {
+        let mut server = Server::new();
+        let mock_quota = server
+            .mock("GET", "/sapi/v1/simple-earn/flexible/personalLeftQuota")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "productId=BTC001&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/personal_left_quota.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let quota = savings.flexible_personal_left_quota("BTC001").unwrap();
+
+        mock_quota.assert();
+
+        assert!(approx_eq!(f64, quota.left_personal_quota, 1000.0, ulps = 2));
+    }
+
+    #[test]
+    fn sweep_dust() {
+        let mut server = Server::new();
+        let mock_dust_assets = server
+            .mock("POST", "/sapi/v1/asset/dust-btc")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "accountType=SPOT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_assets.json")
+            .create();
+        let mock_all_prices = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/savings/all_prices.json")
+            .create();
+        let mock_dust_transfer = server
+            .mock("POST", "/sapi/v1/asset/dust")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^accountType=SPOT&recvWindow=1234&timestamp=\\d+&asset=ADA&asset=XYZ\
+                &signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfer = savings
+            .sweep_dust(&market, "USDT", 5.0, Some(DustAccountType::Spot))
+            .unwrap()
+            .unwrap();
+
+        mock_dust_assets.assert();
+        mock_all_prices.assert();
+        mock_dust_transfer.assert();
+
+        assert!(approx_eq!(
+            f64,
+            transfer.total_transfered,
+            0.01947,
+            ulps = 2
+        ));
+        assert_eq!(transfer.transfer_result.len(), 2);
+        assert_eq!(transfer.transfer_result[1].from_asset, "XYZ");
+        assert_eq!(transfer.transfer_result[1].tran_id, 2970932918);
+    }
+
+    #[test]
+    fn sweep_dust_nothing_under_threshold() {
+        let mut server = Server::new();
+        let mock_dust_assets = server
+            .mock("POST", "/sapi/v1/asset/dust-btc")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_assets.json")
+            .create();
+        let mock_all_prices = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/savings/all_prices.json")
+            .create();
+        let mock_dust_transfer = server
+            .mock("POST", "/sapi/v1/asset/dust")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfer = savings.sweep_dust(&market, "USDT", 1.0, None).unwrap();
+
+        mock_dust_assets.assert();
+        mock_all_prices.assert();
+        mock_dust_transfer.assert();
+
+        assert!(transfer.is_none());
+    }
+
+    #[test]
+    fn dust_under_btc() {
+        let mut server = Server::new();
+        let mock_dust_assets = server
+            .mock("POST", "/sapi/v1/asset/dust-btc")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_assets.json")
+            .create();
+        let mock_all_prices = server
+            .mock("GET", "/api/v3/ticker/price")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let dust = savings.dust_under(&market, "BTC", 0.0001, None).unwrap();
+
+        mock_dust_assets.assert();
+        mock_all_prices.assert();
+
+        assert_eq!(dust.len(), 1);
+        assert_eq!(dust[0].asset, "XYZ");
+    }
+
+    #[test]
+    fn dust_log() {
+        let mut server = Server::new();
+        let mock_dust_log = server
+            .mock("GET", "/sapi/v1/asset/dribblet")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&startTime=1615900000000&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/dust_log.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let dust_log = savings.dust_log(Some(1615900000000), None).unwrap();
+
+        mock_dust_log.assert();
+
+        assert_eq!(dust_log.total, 1);
+        let conversion = &dust_log.user_asset_dribblets[0];
+        assert_eq!(conversion.trans_id, 45178372831);
+        assert_eq!(conversion.user_asset_dribblet_details.len(), 2);
+        assert_eq!(conversion.user_asset_dribblet_details[1].from_asset, "ETH");
+        assert!(approx_eq!(
+            f64,
+            conversion.total_transfered_amount,
+            0.00132256,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn asset_dividend_history() {
+        let mut server = Server::new();
+        let mock_dividend = server
+            .mock("GET", "/sapi/v1/asset/assetDividend")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "asset=BHFT&limit=20&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/asset_dividend.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let savings: Savings = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let dividends = savings
+            .asset_dividend_history(DividendHistoryRequest {
+                asset: Some("BHFT".into()),
+                limit: Some(20),
+                ..Default::default()
+            })
+            .unwrap();
+
+        mock_dividend.assert();
+
+        assert_eq!(dividends.total, 1);
+        assert_eq!(dividends.data[0].en_info, "BHFT distribution");
+        assert_eq!(dividends.data[0].div_time, 1563189166000);
+        assert!(approx_eq!(f64, dividends.data[0].amount, 10.0, ulps = 2));
+    }
+}
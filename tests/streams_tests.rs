@@ -0,0 +1,130 @@
+use binance::futures::account::ContractType;
+use binance::streams::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_names() {
+        assert_eq!(Stream::trade("BTCUSDT").to_string(), "btcusdt@trade");
+        assert_eq!(Stream::agg_trade("BNBBTC").to_string(), "bnbbtc@aggTrade");
+        assert_eq!(
+            Stream::kline("ETHBTC", KlineInterval::OneMonth).to_string(),
+            "ethbtc@kline_1M"
+        );
+        assert_eq!(
+            Stream::partial_depth("BTCUSDT", DepthLevels::Twenty, Some(UpdateSpeed::Ms100))
+                .to_string(),
+            "btcusdt@depth20@100ms"
+        );
+        assert_eq!(
+            Stream::partial_depth("BTCUSDT", DepthLevels::Five, None).to_string(),
+            "btcusdt@depth5"
+        );
+        assert_eq!(
+            Stream::diff_depth("BTCUSDT", Some(UpdateSpeed::Ms500)).to_string(),
+            "btcusdt@depth@500ms"
+        );
+        assert_eq!(
+            Stream::diff_depth("BTCUSDT", None).to_string(),
+            "btcusdt@depth"
+        );
+        assert_eq!(
+            Stream::book_ticker("BTCUSDT").to_string(),
+            "btcusdt@bookTicker"
+        );
+        assert_eq!(Stream::AllMiniTickers.to_string(), "!miniTicker@arr");
+        assert_eq!(Stream::AllTickers.to_string(), "!ticker@arr");
+        assert_eq!(
+            Stream::window_ticker("BTCUSDT", TickerWindow::FourHours).to_string(),
+            "btcusdt@ticker_4h"
+        );
+        assert_eq!(
+            Stream::AllWindowTickers {
+                window: TickerWindow::OneDay
+            }
+            .to_string(),
+            "!ticker_1d@arr"
+        );
+        assert_eq!(
+            Stream::mark_price("BTCUSDT", true).to_string(),
+            "btcusdt@markPrice@1s"
+        );
+        assert_eq!(
+            Stream::AllMarkPrices {
+                every_second: false
+            }
+            .to_string(),
+            "!markPrice@arr"
+        );
+        assert_eq!(Stream::AllForceOrders.to_string(), "!forceOrder@arr");
+        assert_eq!(
+            Stream::continuous_kline(
+                "BTCUSDT",
+                ContractType::CurrentQuarter,
+                KlineInterval::FiveMinutes
+            )
+            .to_string(),
+            "btcusdt_current_quarter@continuousKline_5m"
+        );
+        assert_eq!(
+            Stream::user_data("pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1")
+                .to_string(),
+            "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+        );
+    }
+
+    #[test]
+    fn stream_event_types() {
+        assert_eq!(
+            Stream::trade("BTCUSDT").event_type(),
+            Some(EventType::Trade)
+        );
+        assert_eq!(
+            Stream::kline("BTCUSDT", KlineInterval::OneMinute).event_type(),
+            Some(EventType::Kline)
+        );
+        assert_eq!(
+            Stream::diff_depth("BTCUSDT", None).event_type(),
+            Some(EventType::DepthUpdate)
+        );
+        assert_eq!(
+            Stream::partial_depth("BTCUSDT", DepthLevels::Ten, None).event_type(),
+            None
+        );
+        assert_eq!(Stream::book_ticker("BTCUSDT").event_type(), None);
+        assert_eq!(
+            Stream::window_ticker("BTCUSDT", TickerWindow::OneHour).event_type(),
+            Some(EventType::WindowTicker(TickerWindow::OneHour))
+        );
+        assert_eq!(
+            Stream::mark_price("BTCUSDT", false).event_type(),
+            Some(EventType::MarkPriceUpdate)
+        );
+        assert_eq!(
+            Stream::continuous_kline("BTCUSDT", ContractType::Perpetual, KlineInterval::OneHour)
+                .event_type(),
+            Some(EventType::ContinuousKline)
+        );
+        assert_eq!(Stream::user_data("listen-key").event_type(), None);
+    }
+
+    #[test]
+    fn event_type_names() {
+        assert_eq!(EventType::Trade.to_string(), "trade");
+        assert_eq!(EventType::DepthUpdate.to_string(), "depthUpdate");
+        assert_eq!(EventType::MiniTicker.to_string(), "24hrMiniTicker");
+        assert_eq!(
+            EventType::WindowTicker(TickerWindow::OneHour).to_string(),
+            "1hTicker"
+        );
+        assert_eq!(EventType::ContinuousKline.to_string(), "continuous_kline");
+    }
+
+    #[test]
+    fn kline_interval_as_string() {
+        let interval: String = KlineInterval::FifteenMinutes.into();
+        assert_eq!(interval, "15m");
+    }
+}
@@ -0,0 +1,293 @@
+use binance::api::*;
+use binance::config::*;
+use binance::sub_account::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use float_cmp::*;
+
+    #[test]
+    fn list_pages() {
+        let mut server = Server::new();
+        let mock_list = server
+            .mock("GET", "/sapi/v1/sub-account/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "isFreeze=false&limit=200&page=1&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/list.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let sub_accounts = sub_account
+            .list_pages(SubAccountListRequest {
+                is_freeze: Some(false),
+                ..Default::default()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        mock_list.assert();
+
+        assert_eq!(sub_accounts.len(), 2);
+        assert_eq!(sub_accounts[0].email, "alice_virtual@aasaixwqnoemail.com");
+        assert!(sub_accounts[1].is_freeze);
+        assert_eq!(sub_accounts[1].create_time, 1544433328000);
+    }
+
+    #[test]
+    fn spot_summary() {
+        let mut server = Server::new();
+        let mock_spot_summary = server
+            .mock("GET", "/sapi/v1/sub-account/spotSummary")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "page=1&recvWindow=1234&size=20&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/spot_summary.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let summary = sub_account.spot_summary(None, Some(1), Some(20)).unwrap();
+
+        mock_spot_summary.assert();
+
+        assert_eq!(summary.total_count, 2);
+        assert!(approx_eq!(
+            f64,
+            summary.master_account_total_asset,
+            0.23231201,
+            ulps = 2
+        ));
+        assert_eq!(summary.sub_accounts.len(), 2);
+        assert!(approx_eq!(
+            f64,
+            summary.sub_accounts[0].total_asset,
+            9999.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn usdm_futures_summary() {
+        let mut server = Server::new();
+        let mock_futures_summary = server
+            .mock("GET", "/sapi/v2/sub-account/futures/accountSummary")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "futuresType=1&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/usdm_futures_summary.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let summary = sub_account.usdm_futures_summary(None, None).unwrap();
+
+        mock_futures_summary.assert();
+
+        assert_eq!(summary.asset, "USD");
+        assert!(approx_eq!(
+            f64,
+            summary.total_wallet_balance,
+            22.15879444,
+            ulps = 2
+        ));
+        assert_eq!(summary.sub_account_list.len(), 1);
+        assert!(approx_eq!(
+            f64,
+            summary.sub_account_list[0].total_open_order_initial_margin,
+            9.0,
+            ulps = 2
+        ));
+    }
+
+    #[test]
+    fn universal_transfer() {
+        let mut server = Server::new();
+        let mock_transfer = server
+            .mock("POST", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "amount=25&asset=USDT&clientTranId=rebalance-1&fromAccountType=SPOT\
+                &recvWindow=1234&timestamp=\\d+&toAccountType=USDT_FUTURE\
+                &toEmail=alice_virtual%40aasaixwqnoemail.com&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/universal_transfer.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let mut transfer = SubAccountTransferRequest::new(
+            "USDT",
+            25.0,
+            SubAccountWallet::Spot,
+            SubAccountWallet::UsdtFuture,
+        );
+        transfer.to_email = Some("alice_virtual@aasaixwqnoemail.com".into());
+        transfer.client_tran_id = Some("rebalance-1".into());
+        let transaction = sub_account.universal_transfer(transfer).unwrap();
+
+        mock_transfer.assert();
+
+        assert_eq!(transaction.tran_id, 11945860693);
+        assert_eq!(transaction.client_tran_id, Some("rebalance-1".into()));
+    }
+
+    #[test]
+    fn universal_transfer_validation() {
+        let server = Server::new();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+
+        let mut transfer = SubAccountTransferRequest::new(
+            "USDT",
+            25.0,
+            SubAccountWallet::Spot,
+            SubAccountWallet::IsolatedMargin,
+        );
+        transfer.to_email = Some("alice_virtual@aasaixwqnoemail.com".into());
+        assert!(sub_account.universal_transfer(transfer).is_err());
+
+        let transfer = SubAccountTransferRequest::new(
+            "USDT",
+            25.0,
+            SubAccountWallet::Spot,
+            SubAccountWallet::Spot,
+        );
+        assert!(sub_account.universal_transfer(transfer).is_err());
+    }
+
+    #[test]
+    fn universal_transfer_history() {
+        let mut server = Server::new();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "limit=500&page=1&recvWindow=1234&timestamp=\\d+\
+                &toEmail=alice_virtual%40aasaixwqnoemail.com&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/universal_transfer_history.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let transfers = sub_account
+            .universal_transfer_history_pages(SubAccountTransferHistoryRequest {
+                to_email: Some("alice_virtual@aasaixwqnoemail.com".into()),
+                ..Default::default()
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        mock_history.assert();
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].tran_id, 92275823339);
+        assert_eq!(transfers[0].from_account_type, "USDT_FUTURE");
+        assert_eq!(transfers[0].status, "SUCCESS");
+        assert!(approx_eq!(f64, transfers[0].amount, 0.01, ulps = 2));
+    }
+
+    #[test]
+    fn update_ip_restriction() {
+        let mut server = Server::new();
+        let mock_update = server
+            .mock("POST", "/sapi/v2/sub-account/subAccountApi/ipRestriction")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=alice_virtual%40aasaixwqnoemail.com&ipAddress=69.210.67.14,8.34.21.10\
+                &recvWindow=1234&status=2&subAccountApiKey=k5V49&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/update_ip_restriction.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let restriction = sub_account
+            .update_ip_restriction(
+                "alice_virtual@aasaixwqnoemail.com",
+                "k5V49",
+                true,
+                &["69.210.67.14", "8.34.21.10"],
+            )
+            .unwrap();
+
+        mock_update.assert();
+
+        assert!(restriction.ip_restrict);
+        assert_eq!(restriction.ip_list, vec!["69.210.67.14", "8.34.21.10"]);
+        assert_eq!(restriction.update_time, 1636371437000);
+    }
+
+    #[test]
+    fn delete_ip_restriction_without_ip() {
+        let server = Server::new();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+
+        let result =
+            sub_account.delete_ip_restriction("alice_virtual@aasaixwqnoemail.com", "k5V49", &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn ip_restriction() {
+        let mut server = Server::new();
+        let mock_restriction = server
+            .mock("GET", "/sapi/v1/sub-account/subAccountApi/ipRestriction")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "email=alice%2Bbot%40example.com&recvWindow=1234&subAccountApiKey=k5V49\
+                &timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/sub_account/ip_restriction.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let email = String::from("alice+bot@example.com");
+        let restriction = sub_account.ip_restriction(email, "k5V49").unwrap();
+
+        mock_restriction.assert();
+
+        assert!(restriction.ip_restrict);
+        assert_eq!(restriction.ip_list, vec!["69.210.67.14"]);
+    }
+}
@@ -0,0 +1,484 @@
+use binance::config::*;
+use binance::futures::websockets::*;
+use binance::streams::Stream;
+use binance::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::{Duration, Instant};
+    use binance::errors::SdkError;
+    use serde_json::{json, Value};
+    use tungstenite::handshake::server::{Callback, ErrorResponse, Request, Response};
+    use tungstenite::{accept, accept_hdr, Message};
+
+    const TRADE: &str = r#"{"e":"trade","E":1672515782136,"s":"BTCUSDT","t":12345,"p":"0.001","q":"100","b":88,"a":50,"T":1672515782136,"m":true,"M":true}"#;
+
+    #[test]
+    fn event_loop_with_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Sends one trade per connection and drops the first connection
+        let server = thread::spawn(move || {
+            for _ in 0..2 {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = accept(stream).unwrap();
+                socket.send(Message::Text(TRADE.into())).unwrap();
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            }
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            match event {
+                WebsocketEvent::Trade(_) => events.push("trade".to_string()),
+                WebsocketEvent::Connected => events.push("connected".to_string()),
+                WebsocketEvent::Disconnected(_) => events.push("disconnected".to_string()),
+                WebsocketEvent::Reconnected { attempts } => {
+                    events.push(format!("reconnected after {}", attempts))
+                }
+                _ => (),
+            }
+            if events.len() == 5 {
+                running.store(false, Ordering::Relaxed);
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        web_socket
+            .event_loop_with_reconnect(&running, &policy)
+            .unwrap();
+        assert_eq!(web_socket.streams(), ["btcusdt@trade"]);
+        drop(web_socket);
+        server.join().unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                "connected",
+                "trade",
+                "disconnected",
+                "reconnected after 1",
+                "trade"
+            ]
+        );
+    }
+
+    #[test]
+    fn event_loop_with_reconnect_gives_up() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Accepts a single connection, then stops listening
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            drop(listener);
+            let mut socket = accept(stream).unwrap();
+            socket.close(None).unwrap();
+            while socket.read().is_ok() {}
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let mut disconnects = 0;
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Disconnected(_) = event {
+                disconnects += 1;
+            }
+            Ok(())
+        });
+        assert!(web_socket
+            .event_loop_with_reconnect(&AtomicBool::new(true), &ReconnectPolicy::default())
+            .is_err());
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(2),
+            ..Default::default()
+        };
+        assert!(web_socket
+            .event_loop_with_reconnect(&AtomicBool::new(true), &policy)
+            .is_err());
+        drop(web_socket);
+        server.join().unwrap();
+
+        assert_eq!(disconnects, 1);
+    }
+
+    #[test]
+    fn live_subscriptions() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = accept(stream).unwrap();
+            let mut expected_id = 1;
+            while let Ok(message) = socket.read() {
+                let Message::Text(request) = message else {
+                    continue;
+                };
+                let request: Value = serde_json::from_str(&request).unwrap();
+                let id = request["id"].as_u64().unwrap();
+                assert_eq!(id, expected_id);
+                expected_id += 1;
+                let response = match request["method"].as_str().unwrap() {
+                    "SUBSCRIBE" => {
+                        assert_eq!(request["params"], json!(["ethusdt@trade"]));
+                        socket.send(Message::Text(TRADE.into())).unwrap();
+                        json!({"result": null, "id": id})
+                    }
+                    "UNSUBSCRIBE" => json!({"result": null, "id": id}),
+                    "LIST_SUBSCRIPTIONS" => {
+                        assert!(request.get("params").is_none());
+                        json!({"result": ["btcusdt@trade", "ethusdt@trade"], "id": id})
+                    }
+                    _ => json!({"error": {"code": 2, "msg": "Invalid request"}, "id": id}),
+                };
+                socket.send(Message::Text(response.to_string())).unwrap();
+            }
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let mut trades = 0;
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Trade(_) = event {
+                trades += 1;
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        let start = Instant::now();
+
+        web_socket.subscribe(&[Stream::trade("ETHUSDT")]).unwrap();
+        assert_eq!(web_socket.streams(), ["btcusdt@trade", "ethusdt@trade"]);
+        assert_eq!(
+            web_socket.list_subscriptions().unwrap(),
+            vec!["btcusdt@trade", "ethusdt@trade"]
+        );
+        match web_socket.set_combined(true) {
+            Err(SdkError::BinanceError(error)) => {
+                assert_eq!(error.code, 2);
+                assert_eq!(error.msg, "Invalid request");
+            }
+            _ => panic!("expected a BinanceError"),
+        }
+        web_socket
+            .unsubscribe(&["btcusdt@trade".to_string()])
+            .unwrap();
+        assert_eq!(web_socket.streams(), ["ethusdt@trade"]);
+
+        // The sixth control message within a second waits for the rate limit
+        web_socket.list_subscriptions().unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+        web_socket.list_subscriptions().unwrap();
+        assert!(start.elapsed() >= Duration::from_secs(1));
+
+        web_socket.disconnect().unwrap();
+        drop(web_socket);
+        server.join().unwrap();
+
+        assert_eq!(trades, 1);
+    }
+
+    #[test]
+    fn unanswered_subscription_times_out() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Leaves the subscription unanswered and sends a trade once the client gave up on it
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = accept(stream).unwrap();
+            let request = socket.read().unwrap().into_text().unwrap();
+            let request: Value = serde_json::from_str(&request).unwrap();
+            assert_eq!(request["method"], "SUBSCRIBE");
+            thread::sleep(Duration::from_millis(2_500));
+            socket.send(Message::Text(TRADE.into())).unwrap();
+            while socket.read().is_ok() {}
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let running = AtomicBool::new(true);
+        let trades = Cell::new(0);
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Trade(_) = event {
+                trades.set(trades.get() + 1);
+                running.store(false, Ordering::Relaxed);
+            }
+            Ok(())
+        })
+        .set_control_response_timeout(Duration::from_secs(1));
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+
+        let start = Instant::now();
+        assert!(web_socket.subscribe(&[Stream::trade("ETHUSDT")]).is_err());
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(trades.get(), 0);
+        assert_eq!(web_socket.streams(), ["btcusdt@trade"]);
+
+        // Reads block again once the control exchange is over
+        web_socket.event_loop(&running).unwrap();
+        assert_eq!(trades.get(), 1);
+        drop(web_socket);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn resubscribe_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Confirms the subscription on both connections and drops the first one
+        let server = thread::spawn(move || {
+            for connection in 0..2 {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = accept(stream).unwrap();
+                let request = socket.read().unwrap().into_text().unwrap();
+                let request: Value = serde_json::from_str(&request).unwrap();
+                assert_eq!(request["method"], "SUBSCRIBE");
+                assert_eq!(request["params"], json!(["ethusdt@trade"]));
+                let response = json!({"result": null, "id": request["id"]});
+                socket.send(Message::Text(response.to_string())).unwrap();
+                if connection == 1 {
+                    socket.send(Message::Text(TRADE.into())).unwrap();
+                }
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            }
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            match event {
+                WebsocketEvent::Trade(_) => {
+                    events.push("trade");
+                    running.store(false, Ordering::Relaxed);
+                }
+                WebsocketEvent::Disconnected(_) => events.push("disconnected"),
+                WebsocketEvent::Reconnected { .. } => events.push("reconnected"),
+                _ => (),
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        web_socket
+            .subscribe(&["ethusdt@trade".to_string()])
+            .unwrap();
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        web_socket
+            .event_loop_with_reconnect(&running, &policy)
+            .unwrap();
+        drop(web_socket);
+        server.join().unwrap();
+
+        assert_eq!(events, vec!["disconnected", "reconnected", "trade"]);
+    }
+
+    #[test]
+    fn resubscribe_failure_is_retried() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Drops the first connection, leaves the subscription of the second unanswered
+        // and confirms it on the third
+        let server = thread::spawn(move || {
+            for connection in 0..3 {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = accept(stream).unwrap();
+                let request = socket.read().unwrap().into_text().unwrap();
+                let request: Value = serde_json::from_str(&request).unwrap();
+                assert_eq!(request["method"], "SUBSCRIBE");
+                if connection != 1 {
+                    let response = json!({"result": null, "id": request["id"]});
+                    socket.send(Message::Text(response.to_string())).unwrap();
+                }
+                if connection == 2 {
+                    socket.send(Message::Text(TRADE.into())).unwrap();
+                }
+                socket.close(None).unwrap();
+                while socket.read().is_ok() {}
+            }
+        });
+
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            match event {
+                WebsocketEvent::Trade(_) => {
+                    events.push("trade".to_string());
+                    running.store(false, Ordering::Relaxed);
+                }
+                WebsocketEvent::Disconnected(_) => events.push("disconnected".to_string()),
+                WebsocketEvent::Reconnected { attempts } => {
+                    events.push(format!("reconnected after {}", attempts));
+                }
+                _ => (),
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        web_socket
+            .subscribe(&["ethusdt@trade".to_string()])
+            .unwrap();
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(10),
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        web_socket
+            .event_loop_with_reconnect(&running, &policy)
+            .unwrap();
+        drop(web_socket);
+        server.join().unwrap();
+
+        assert_eq!(events, vec!["disconnected", "reconnected after 2", "trade"]);
+    }
+
+    // Handshake callback keeping the request uri
+    struct RecordPath<'a>(&'a mut String);
+
+    impl Callback for RecordPath<'_> {
+        fn on_request(
+            self, request: &Request, response: Response,
+        ) -> Result<Response, ErrorResponse> {
+            *self.0 = request.uri().to_string();
+            Ok(response)
+        }
+    }
+
+    // Accepts one connection and returns the path and query it was opened on
+    fn serve_once() -> (u16, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut path = String::new();
+            let _socket = accept_hdr(stream, RecordPath(&mut path)).unwrap();
+            path
+        });
+        (port, server)
+    }
+
+    #[test]
+    fn multiple_streams_with_config() {
+        let (port, server) = serve_once();
+        let config = Config::default().set_ws_endpoint(format!("ws://127.0.0.1:{}/ws/", port));
+        let mut web_socket = WebSockets::new(|_: WebsocketEvent| Ok(()));
+        web_socket
+            .connect_multiple_streams_with_config(
+                &[Stream::trade("BTCUSDT"), Stream::book_ticker("ETHUSDT")],
+                &config,
+            )
+            .unwrap();
+        drop(web_socket);
+
+        assert_eq!(
+            server.join().unwrap(),
+            "/stream?streams=btcusdt@trade/ethusdt@bookTicker"
+        );
+    }
+
+    #[test]
+    fn futures_connect_with_config() {
+        let (port, server) = serve_once();
+        let config =
+            Config::default().set_futures_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let mut web_socket = FuturesWebSockets::new(|_: FuturesWebsocketEvent| Ok(()));
+        web_socket
+            .connect_with_config(&FuturesMarket::USDM, "btcusdt@markPrice", &config)
+            .unwrap();
+        drop(web_socket);
+        assert_eq!(server.join().unwrap(), "/ws/btcusdt@markPrice");
+
+        let (port, server) = serve_once();
+        let config =
+            Config::default().set_delivery_ws_endpoint(format!("ws://127.0.0.1:{}/ws", port));
+        let mut web_socket = FuturesWebSockets::new(|_: FuturesWebsocketEvent| Ok(()));
+        web_socket
+            .connect_multiple_streams_with_config(
+                &FuturesMarket::COINM,
+                &["btcusd_perp@aggTrade", "!markPrice@arr"],
+                &config,
+            )
+            .unwrap();
+        drop(web_socket);
+        assert_eq!(
+            server.join().unwrap(),
+            "/stream?streams=btcusd_perp@aggTrade/!markPrice@arr"
+        );
+
+        let (port, server) = serve_once();
+        let config = Config::default()
+            .set_portfolio_margin_ws_endpoint(format!("ws://127.0.0.1:{}/pm/ws", port));
+        let mut web_socket = FuturesWebSockets::new(|_: FuturesWebsocketEvent| Ok(()));
+        web_socket
+            .connect_with_config(&FuturesMarket::PortfolioMargin, "pm-listen-key", &config)
+            .unwrap();
+        drop(web_socket);
+        assert_eq!(server.join().unwrap(), "/pm/ws/pm-listen-key");
+    }
+
+    #[test]
+    fn config_validate() {
+        Config::default().validate().unwrap();
+        let testnet = Config::testnet();
+        assert!(testnet.futures_ws_endpoint.starts_with("wss://"));
+        // Options and portfolio margin have no testnet
+        assert!(testnet.options_rest_api_endpoint.is_empty());
+        assert!(testnet.portfolio_margin_ws_endpoint.is_empty());
+        assert!(matches!(testnet.validate(), Err(SdkError::Other(_))));
+        Config::testnet()
+            .set_options_rest_api_endpoint("https://eapi.binance.com")
+            .set_options_ws_endpoint("wss://nbstream.binance.com/eoptions/ws")
+            .set_portfolio_margin_rest_api_endpoint("https://papi.binance.com")
+            .set_portfolio_margin_ws_endpoint("wss://fstream.binance.com/pm/ws")
+            .validate()
+            .unwrap();
+
+        let config = Config::default().set_futures_ws_endpoint("https://fstream.binance.com/ws");
+        assert!(matches!(config.validate(), Err(SdkError::Other(_))));
+        let mut web_socket = FuturesWebSockets::new(|_: FuturesWebsocketEvent| Ok(()));
+        assert!(matches!(
+            web_socket.connect_with_config(&FuturesMarket::USDM, "btcusdt@trade", &config),
+            Err(SdkError::Other(_))
+        ));
+
+        let config = Config::default().set_rest_api_endpoint("wss://api.binance.com");
+        assert!(matches!(config.validate(), Err(SdkError::Other(_))));
+
+        // No `/ws` to derive the combined stream endpoint from
+        let config = Config::default().set_ws_endpoint("wss://stream.binance.com:9443");
+        assert!(matches!(config.validate(), Err(SdkError::Other(_))));
+        let mut web_socket = WebSockets::new(|_: WebsocketEvent| Ok(()));
+        assert!(matches!(
+            web_socket.connect_multiple_streams_with_config(&["btcusdt@trade"], &config),
+            Err(SdkError::Other(_))
+        ));
+    }
+}